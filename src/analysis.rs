@@ -0,0 +1,1111 @@
+//! Capture-level analysis over parsed MIL-STD-1553B transactions
+
+use crate::core::{Address, Bus, Word};
+use crate::error::{ParseError, Result};
+use crate::message::{Command, CommandType, Message, SubAddress};
+use crate::parser::Transaction;
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
+/// Build an inventory of every distinct command word in a capture
+///
+/// Returns each command with its number of occurrences, sorted by
+/// RT address, sub-address, direction (receive before transmit) and
+/// word count.
+pub fn command_table(transactions: &[Transaction]) -> Vec<(Command, usize)> {
+    let mut counts: HashMap<Command, usize> = HashMap::new();
+    for command in transactions.iter().filter_map(|t| t.message.command()) {
+        *counts.entry(command.clone()).or_insert(0) += 1;
+    }
+
+    let mut table: Vec<(Command, usize)> = counts.into_iter().collect();
+    table.sort_by_key(|(command, _)| {
+        (
+            command.address,
+            command.sub_address.value(),
+            command.command_type == CommandType::Transmit,
+            command.word_count,
+        )
+    });
+    table
+}
+
+/// Select the transactions timestamped within `[start_us, end_us)`
+///
+/// Transactions without a timestamp are excluded. Capture order is kept.
+pub fn filter_by_time(
+    transactions: &[Transaction],
+    start_us: u64,
+    end_us: u64,
+) -> Vec<&Transaction> {
+    transactions
+        .iter()
+        .filter(|t| {
+            t.timestamp_us
+                .is_some_and(|ts| (start_us..end_us).contains(&ts))
+        })
+        .collect()
+}
+
+/// Observed use of one sub-address of an RT
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubAddressUsage {
+    /// Word counts commanded in transmit (RT-to-BC) direction
+    pub transmit: BTreeSet<u16>,
+    /// Word counts commanded in receive (BC-to-RT) direction
+    pub receive: BTreeSet<u16>,
+}
+
+impl SubAddressUsage {
+    /// Check whether the sub-address was used in either direction
+    pub fn is_used(&self) -> bool {
+        !self.transmit.is_empty() || !self.receive.is_empty()
+    }
+}
+
+/// Map the sub-addresses an RT uses, indexed by sub-address
+///
+/// Every command to `address` in the capture contributes its commanded
+/// word count to the entry for its sub-address and direction.
+pub fn subaddress_map(transactions: &[Transaction], address: Address) -> [SubAddressUsage; 32] {
+    let mut map: [SubAddressUsage; 32] = std::array::from_fn(|_| SubAddressUsage::default());
+    for command in transactions
+        .iter()
+        .filter_map(|t| t.message.command())
+        .filter(|command| command.address == address)
+    {
+        let usage = &mut map[command.sub_address.value() as usize];
+        match command.command_type {
+            CommandType::Transmit => usage.transmit.insert(command.word_count),
+            CommandType::Receive => usage.receive.insert(command.word_count),
+        };
+    }
+    map
+}
+
+/// Detect a controller sending the same command twice in a row
+///
+/// Returns the index of every transaction whose command is identical to
+/// the command of the transaction immediately before it, when the two
+/// are timestamped no more than `window` apart. Transactions without a
+/// timestamp are never flagged.
+pub fn detect_stutter(transactions: &[Transaction], window: Duration) -> Vec<usize> {
+    let window_us = window.as_micros() as u64;
+    transactions
+        .windows(2)
+        .enumerate()
+        .filter(|(_, pair)| {
+            let (previous, current) = (&pair[0], &pair[1]);
+            let same_command = matches!(
+                (previous.message.command(), current.message.command()),
+                (Some(a), Some(b)) if a == b
+            );
+            let close = matches!(
+                (previous.timestamp_us, current.timestamp_us),
+                (Some(a), Some(b)) if b.saturating_sub(a) <= window_us
+            );
+            same_command && close
+        })
+        .map(|(index, _)| index + 1)
+        .collect()
+}
+
+/// Detect bursts of mode commands to the same RT
+///
+/// Counts the mode commands sent to each RT over a sliding `window` and
+/// returns the index of every mode command that brings its RT above
+/// `threshold` mode commands within the window ending at it. A storm of
+/// repeated resets, for example, points at a controller fault.
+/// Transactions without a timestamp are ignored. Transactions are assumed
+/// to be in chronological order.
+pub fn detect_mode_code_storms(
+    transactions: &[Transaction],
+    window: Duration,
+    threshold: usize,
+) -> Vec<usize> {
+    let window_us = window.as_micros() as u64;
+    let mut recent: HashMap<Address, VecDeque<u64>> = HashMap::new();
+    let mut flagged = Vec::new();
+
+    for (index, transaction) in transactions.iter().enumerate() {
+        let (Some(command), Some(seen)) = (transaction.message.command(), transaction.timestamp_us)
+        else {
+            continue;
+        };
+        if !command.is_mode_command() {
+            continue;
+        }
+
+        let times = recent.entry(command.address).or_default();
+        while times
+            .front()
+            .is_some_and(|&sent| seen.saturating_sub(sent) > window_us)
+        {
+            times.pop_front();
+        }
+        times.push_back(seen);
+        if times.len() > threshold {
+            flagged.push(index);
+        }
+    }
+
+    flagged
+}
+
+/// Bus switchover counts for a capture
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SwitchoverReport {
+    /// Number of times consecutive transactions changed bus
+    pub total: usize,
+    /// Number of times consecutive transactions to the same RT changed bus
+    pub per_rt: BTreeMap<Address, usize>,
+}
+
+/// Count A/B bus switchovers in a capture
+///
+/// The log is assumed to be in chronological order. Frequent switchovers
+/// for an RT usually point to a flaky primary bus.
+pub fn count_switchovers(transactions: &[Transaction]) -> SwitchoverReport {
+    let mut report = SwitchoverReport::default();
+    let mut last_bus: Option<Bus> = None;
+    let mut last_bus_per_rt: HashMap<Address, Bus> = HashMap::new();
+
+    for transaction in transactions {
+        if last_bus.is_some_and(|bus| bus != transaction.bus) {
+            report.total += 1;
+        }
+        last_bus = Some(transaction.bus);
+
+        let address = transaction.message.address();
+        if let Some(previous) = last_bus_per_rt.insert(address, transaction.bus) {
+            if previous != transaction.bus {
+                *report.per_rt.entry(address).or_insert(0) += 1;
+            }
+        }
+    }
+
+    report
+}
+
+/// A message seen on both buses, with the time between the two copies
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BusSkew {
+    /// Index of the Bus A copy in the capture
+    pub bus_a_index: usize,
+    /// Index of the Bus B copy in the capture
+    pub bus_b_index: usize,
+    /// Bus B timestamp minus Bus A timestamp, in microseconds
+    pub skew_us: i64,
+}
+
+/// Match redundant transmissions across buses and measure their skew
+///
+/// Each timestamped Bus A transaction is paired with the closest
+/// unmatched Bus B transaction carrying an identical message within
+/// `window`. Transactions without a timestamp or a counterpart are
+/// skipped. Pairs are returned in Bus A order.
+pub fn cross_bus_skew(transactions: &[Transaction], window: Duration) -> Vec<BusSkew> {
+    let window_us = window.as_micros() as i64;
+    let mut matched = vec![false; transactions.len()];
+    let mut skews = Vec::new();
+
+    for (a_index, a) in transactions.iter().enumerate() {
+        let Some(a_time) = a.timestamp_us.filter(|_| a.bus == Bus::BusA) else {
+            continue;
+        };
+
+        let closest = transactions
+            .iter()
+            .enumerate()
+            .filter(|(b_index, b)| {
+                b.bus == Bus::BusB && !matched[*b_index] && b.message == a.message
+            })
+            .filter_map(|(b_index, b)| {
+                let skew = b.timestamp_us? as i64 - a_time as i64;
+                (skew.abs() <= window_us).then_some((b_index, skew))
+            })
+            .min_by_key(|(_, skew)| skew.abs());
+
+        if let Some((b_index, skew_us)) = closest {
+            matched[b_index] = true;
+            skews.push(BusSkew {
+                bus_a_index: a_index,
+                bus_b_index: b_index,
+                skew_us,
+            });
+        }
+    }
+
+    skews
+}
+
+/// Sync polarity tally over the first word of each message
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SyncPolarityReport {
+    /// Messages starting with a command/status sync, as expected
+    pub normal: usize,
+    /// Messages starting with a data sync
+    pub inverted: usize,
+}
+
+impl SyncPolarityReport {
+    /// Check whether the capture points to reversed bus wiring
+    ///
+    /// Inverted wiring swaps the two sync patterns, so most messages then
+    /// appear to start with a data word.
+    pub fn is_reversed(&self) -> bool {
+        self.inverted > self.normal
+    }
+}
+
+/// Tally the sync polarity of each message's leading word
+///
+/// `messages` holds the raw 20-bit words of each message, as returned by
+/// [`crate::encoding::ManchesterDecoder::decode_word`]. Raw values are
+/// used because inverted wiring also breaks parity on every word, so such
+/// captures cannot be turned into [`Word`]s. Every message must start
+/// with a command (or status) word, both of which carry the command/status
+/// sync. Words with an invalid sync and empty messages are not counted.
+pub fn sync_polarity(messages: &[Vec<u32>]) -> SyncPolarityReport {
+    let mut report = SyncPolarityReport::default();
+    for &raw in messages.iter().filter_map(|words| words.first()) {
+        match ((raw >> 18) & 0x3) as u8 {
+            Word::SYNC_COMMAND_STATUS => report.normal += 1,
+            Word::SYNC_DATA => report.inverted += 1,
+            _ => {}
+        }
+    }
+    report
+}
+
+/// A busy response linked to the transaction that eventually succeeded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BusyRetry {
+    /// Index of the transaction answered with Busy
+    pub busy_index: usize,
+    /// Index of the next successful transaction to the same RT/sub-address
+    pub retry_index: usize,
+    /// Time from the busy response to the success, if both are timestamped
+    pub latency_us: Option<u64>,
+}
+
+/// Link busy responses to the retry that succeeded
+///
+/// A transaction is busy when its status word has the Busy flag set and
+/// successful when its status word has neither Busy nor Message Error
+/// set. Every status word of a message counts, including the one that
+/// answers a BC-to-RT transfer and both statuses of an RT-to-RT transfer,
+/// each for the RT and sub-address of the command it answers. Each busy
+/// transaction is linked to the next successful one for the same RT and
+/// sub-address, so repeated busy responses share a retry. Busy responses
+/// that are never resolved are omitted.
+pub fn busy_retries(transactions: &[Transaction]) -> Vec<BusyRetry> {
+    let mut pending: HashMap<(Address, SubAddress), Vec<usize>> = HashMap::new();
+    let mut retries = Vec::new();
+
+    for (index, transaction) in transactions.iter().enumerate() {
+        let responses = match &transaction.message {
+            Message::RtToBc {
+                command, status, ..
+            }
+            | Message::CommandStatus { command, status }
+            | Message::BcToRt {
+                command,
+                status: Some(status),
+                ..
+            } => vec![(command, status)],
+            Message::RtToRt {
+                receive_command,
+                transmit_command,
+                receiving_status,
+                transmitting_status,
+                ..
+            } => {
+                let mut responses = vec![(transmit_command, transmitting_status)];
+                if let Some(receiving_status) = receiving_status {
+                    responses.push((receive_command, receiving_status));
+                }
+                responses
+            }
+            _ => continue,
+        };
+
+        for (command, status) in responses {
+            let key = (command.address, command.sub_address);
+            if status.flags.busy {
+                pending.entry(key).or_default().push(index);
+            } else if !status.flags.parity_error {
+                for busy_index in pending.remove(&key).unwrap_or_default() {
+                    let latency_us = match (
+                        transactions[busy_index].timestamp_us,
+                        transaction.timestamp_us,
+                    ) {
+                        (Some(busy), Some(done)) => Some(done.saturating_sub(busy)),
+                        _ => None,
+                    };
+                    retries.push(BusyRetry {
+                        busy_index,
+                        retry_index: index,
+                        latency_us,
+                    });
+                }
+            }
+        }
+    }
+
+    retries
+}
+
+/// Data throughput of a capture, in bytes per second
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThroughputReport {
+    /// Throughput of data words on Bus A
+    pub bus_a: f64,
+    /// Throughput of data words on Bus B
+    pub bus_b: f64,
+    /// Throughput of data words on both buses
+    pub overall: f64,
+}
+
+/// Compute the application data throughput of a capture
+///
+/// Sums 2 bytes per data word and divides by the span from the first to
+/// the last timestamp. Both buses are measured over the same span, so the
+/// per-bus figures add up to the overall one. Transactions without a
+/// timestamp still count towards the data volume. Returns `None` when the
+/// capture spans no time.
+pub fn throughput(transactions: &[Transaction]) -> Option<ThroughputReport> {
+    let timestamps = transactions.iter().filter_map(|t| t.timestamp_us);
+    let first = timestamps.clone().min()?;
+    let last = timestamps.max()?;
+    if last == first {
+        return None;
+    }
+    let seconds = (last - first) as f64 / 1_000_000.0;
+
+    let bytes = |bus: Bus| -> usize {
+        transactions
+            .iter()
+            .filter(|t| t.bus == bus)
+            .map(|t| t.message.data_words().len() * 2)
+            .sum()
+    };
+    let (bus_a, bus_b) = (bytes(Bus::BusA), bytes(Bus::BusB));
+
+    Some(ThroughputReport {
+        bus_a: bus_a as f64 / seconds,
+        bus_b: bus_b as f64 / seconds,
+        overall: (bus_a + bus_b) as f64 / seconds,
+    })
+}
+
+/// Reassemble a data block split across consecutive messages
+///
+/// All transactions must carry a command to the same RT and sub-address;
+/// their 16-bit data payloads are concatenated in order.
+pub fn reassemble_payload(transactions: &[Transaction]) -> Result<Vec<u16>> {
+    let mut target = None;
+    let mut payload = Vec::new();
+
+    for (index, transaction) in transactions.iter().enumerate() {
+        let command = transaction.message.command().ok_or_else(|| {
+            ParseError::invalid_message_type(format!("Transaction {} has no command word", index))
+        })?;
+
+        let key = (command.address, command.sub_address);
+        match target {
+            None => target = Some(key),
+            Some(expected) if expected != key => {
+                return Err(ParseError::validation_error(format!(
+                    "Transaction {} targets {} SA-{}, expected {} SA-{}",
+                    index,
+                    key.0,
+                    key.1.value(),
+                    expected.0,
+                    expected.1.value()
+                )));
+            }
+            Some(_) => {}
+        }
+
+        payload.extend(
+            transaction
+                .message
+                .data_words()
+                .iter()
+                .map(|word| word.get_data_bits()),
+        );
+    }
+
+    Ok(payload)
+}
+
+/// Find missing sequence numbers in a capture
+///
+/// Returns each run of sequence numbers skipped between consecutive
+/// numbered transactions, in capture order. Transactions without a
+/// sequence number are ignored, as are repeated or decreasing numbers.
+pub fn sequence_gaps(transactions: &[Transaction]) -> Vec<RangeInclusive<u32>> {
+    let mut gaps = Vec::new();
+    let mut last: Option<u32> = None;
+
+    for sequence in transactions.iter().filter_map(|t| t.sequence) {
+        if let Some(previous) = last {
+            if sequence > previous.saturating_add(1) {
+                gaps.push(previous + 1..=sequence - 1);
+            }
+        }
+        last = Some(sequence);
+    }
+
+    gaps
+}
+
+/// Find the worst command-to-status response gap in a capture
+///
+/// Messages that carry their own status word (`CommandStatus`, `RtToBc`,
+/// answered `BcToRt` and `RtToRt`) contribute their measured
+/// `response_gap_us`. A status-only transaction is paired with the
+/// preceding command to the same RT that was logged without a status
+/// word, and the gap runs from the end of that command's last word to the
+/// status timestamp. Returns the largest gap in microseconds together
+/// with the index of the transaction holding the status, or `None` if
+/// nothing was measured. Transactions are assumed to be in chronological
+/// order.
+pub fn worst_case_latency(transactions: &[Transaction]) -> Option<(u64, usize)> {
+    let mut worst: Option<(u64, usize)> = None;
+    let mut pending: Option<(Address, u64)> = None;
+
+    for (index, transaction) in transactions.iter().enumerate() {
+        let mut gap = None;
+        match &transaction.message {
+            Message::Status(status) => {
+                if let (Some((address, sent_end)), Some(seen)) = (pending, transaction.timestamp_us)
+                {
+                    if address == status.address {
+                        gap = Some(seen.saturating_sub(sent_end));
+                    }
+                }
+                pending = None;
+            }
+            Message::CommandOnly(command)
+            | Message::BcToRt {
+                command,
+                status: None,
+                ..
+            } => {
+                let duration =
+                    transaction.message.total_words() as u64 * crate::spec::WORD_TIME_US as u64;
+                pending = transaction
+                    .timestamp_us
+                    .map(|sent| (command.address, sent + duration));
+            }
+            _ => {
+                gap = transaction.response_gap_us;
+                pending = None;
+            }
+        }
+
+        if let Some(gap) = gap {
+            if worst.is_none_or(|(max, _)| gap > max) {
+                worst = Some((gap, index));
+            }
+        }
+    }
+
+    worst
+}
+
+/// Outcome of a loopback (write, then read back) check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoopbackResult {
+    /// The data read back matches the data written
+    Match,
+    /// The data differs, starting at `index`
+    ///
+    /// `written` or `read` is `None` when that side ran out of words.
+    Mismatch {
+        index: usize,
+        written: Option<u16>,
+        read: Option<u16>,
+    },
+}
+
+/// Verify a loopback self-test
+///
+/// `write` must be a BC-to-RT transfer and `read` an RT-to-BC transfer
+/// from the same RT and sub-address. The data read back is compared word
+/// by word with the data written.
+pub fn verify_loopback(write: &Transaction, read: &Transaction) -> Result<LoopbackResult> {
+    let Message::BcToRt {
+        command: write_command,
+        ..
+    } = &write.message
+    else {
+        return Err(ParseError::invalid_message_type(
+            "Loopback write must be a BC-to-RT transfer",
+        ));
+    };
+    let Message::RtToBc {
+        command: read_command,
+        ..
+    } = &read.message
+    else {
+        return Err(ParseError::invalid_message_type(
+            "Loopback read must be an RT-to-BC transfer",
+        ));
+    };
+
+    if (write_command.address, write_command.sub_address)
+        != (read_command.address, read_command.sub_address)
+    {
+        return Err(ParseError::validation_error(
+            "Loopback write and read target different RT/sub-address",
+        ));
+    }
+
+    let written = write.data_values();
+    let read = read.data_values();
+    for index in 0..written.len().max(read.len()) {
+        let (w, r) = (written.get(index).copied(), read.get(index).copied());
+        if w != r {
+            return Ok(LoopbackResult::Mismatch {
+                index,
+                written: w,
+                read: r,
+            });
+        }
+    }
+
+    Ok(LoopbackResult::Match)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Word, WordType};
+    use crate::message::{StatusFlags, StatusWord, SubAddress};
+
+    fn command(rt: u8, command_type: CommandType, sa: u8, wc: u16) -> Command {
+        Command::new(
+            Address::new(rt).unwrap(),
+            command_type,
+            SubAddress::new(sa).unwrap(),
+            wc,
+        )
+        .unwrap()
+    }
+
+    fn transaction(message: Message) -> Transaction {
+        Transaction {
+            bus: Bus::BusA,
+            message,
+            timestamp_us: None,
+            response_gap_us: None,
+            sequence: None,
+            decoded: None,
+        }
+    }
+
+    fn transaction_at(bus: Bus, timestamp_us: u64, message: Message) -> Transaction {
+        Transaction {
+            bus,
+            message,
+            timestamp_us: Some(timestamp_us),
+            response_gap_us: None,
+            sequence: None,
+            decoded: None,
+        }
+    }
+
+    #[test]
+    fn test_command_table_counts() {
+        let nav = command(5, CommandType::Transmit, 3, 4);
+        let cfg = command(2, CommandType::Receive, 1, 2);
+        let status = StatusWord::new(
+            Address::new(5).unwrap(),
+            StatusFlags::new(false, false, false, false, false),
+            0,
+        )
+        .unwrap();
+
+        let capture = vec![
+            transaction(Message::CommandOnly(nav.clone())),
+            transaction(Message::BcToRt {
+                command: cfg.clone(),
+                data_words: Vec::new(),
+                status: None,
+            }),
+            transaction(Message::Status(status)),
+            transaction(Message::CommandOnly(nav.clone())),
+            transaction(Message::CommandOnly(nav.clone())),
+        ];
+
+        let table = command_table(&capture);
+        assert_eq!(table, vec![(cfg, 1), (nav, 3)]);
+    }
+
+    #[test]
+    fn test_filter_by_time() {
+        let nav = Message::CommandOnly(command(5, CommandType::Transmit, 1, 4));
+        let mut capture: Vec<Transaction> = [0, 100, 200, 300, 400]
+            .into_iter()
+            .map(|ts| transaction_at(Bus::BusA, ts, nav.clone()))
+            .collect();
+        capture.insert(2, transaction(nav));
+
+        let window: Vec<Option<u64>> = filter_by_time(&capture, 100, 300)
+            .iter()
+            .map(|t| t.timestamp_us)
+            .collect();
+        assert_eq!(window, vec![Some(100), Some(200)]);
+        assert!(filter_by_time(&capture, 500, 600).is_empty());
+    }
+
+    #[test]
+    fn test_subaddress_map() {
+        let capture = vec![
+            transaction(Message::CommandOnly(command(
+                5,
+                CommandType::Transmit,
+                3,
+                4,
+            ))),
+            transaction(Message::CommandOnly(command(
+                5,
+                CommandType::Transmit,
+                3,
+                8,
+            ))),
+            transaction(Message::CommandOnly(command(5, CommandType::Receive, 3, 2))),
+            transaction(Message::CommandOnly(command(
+                5,
+                CommandType::Receive,
+                10,
+                32,
+            ))),
+            transaction(Message::CommandOnly(command(
+                6,
+                CommandType::Transmit,
+                1,
+                1,
+            ))),
+        ];
+
+        let map = subaddress_map(&capture, Address::new(5).unwrap());
+        assert_eq!(map[3].transmit, BTreeSet::from([4, 8]));
+        assert_eq!(map[3].receive, BTreeSet::from([2]));
+        assert!(map[10].transmit.is_empty());
+        assert_eq!(map[10].receive, BTreeSet::from([32]));
+        assert!(!map[1].is_used());
+        assert_eq!(map.iter().filter(|usage| usage.is_used()).count(), 2);
+    }
+
+    #[test]
+    fn test_detect_stutter() {
+        let nav = Message::CommandOnly(command(5, CommandType::Transmit, 1, 4));
+        let cfg = Message::CommandOnly(command(2, CommandType::Receive, 3, 2));
+        let capture = vec![
+            transaction_at(Bus::BusA, 0, nav.clone()),
+            transaction_at(Bus::BusA, 30, nav.clone()),
+            transaction_at(Bus::BusA, 100, cfg.clone()),
+            // Same command again, but well outside the window
+            transaction_at(Bus::BusA, 5_000, cfg),
+        ];
+
+        assert_eq!(detect_stutter(&capture, Duration::from_micros(50)), vec![1]);
+    }
+
+    #[test]
+    fn test_detect_mode_code_storms() {
+        use crate::message::ModeCode;
+
+        let reset = |rt| {
+            Message::CommandOnly(Command::new_mode_code(
+                Address::new(rt).unwrap(),
+                CommandType::Transmit,
+                ModeCode::ResetRemoteTerminal,
+            ))
+        };
+        let nav = Message::CommandOnly(command(5, CommandType::Transmit, 1, 4));
+
+        // Four resets to RT 5 within 300 us, mixed with ordinary traffic
+        let mut capture: Vec<Transaction> = (0..4)
+            .flat_map(|i| {
+                [
+                    transaction_at(Bus::BusA, i * 100, reset(5)),
+                    transaction_at(Bus::BusA, i * 100 + 50, nav.clone()),
+                ]
+            })
+            .collect();
+        // One reset each to other RTs, and a late one to RT 5
+        capture.push(transaction_at(Bus::BusA, 400, reset(6)));
+        capture.push(transaction_at(Bus::BusA, 450, reset(7)));
+        capture.push(transaction_at(Bus::BusA, 10_000, reset(5)));
+
+        let window = Duration::from_micros(500);
+        assert_eq!(detect_mode_code_storms(&capture, window, 2), vec![4, 6]);
+        assert_eq!(detect_mode_code_storms(&capture, window, 3), vec![6]);
+        assert!(detect_mode_code_storms(&capture, window, 4).is_empty());
+    }
+
+    #[test]
+    fn test_count_switchovers() {
+        let rt5 = command(5, CommandType::Transmit, 1, 2);
+        let rt9 = command(9, CommandType::Receive, 1, 2);
+        let buses = [Bus::BusA, Bus::BusB, Bus::BusA, Bus::BusB];
+
+        let mut capture: Vec<Transaction> = buses
+            .iter()
+            .enumerate()
+            .map(|(i, &bus)| {
+                transaction_at(bus, i as u64 * 1000, Message::CommandOnly(rt5.clone()))
+            })
+            .collect();
+        // RT-9 stays on Bus B throughout
+        capture.push(transaction_at(
+            Bus::BusB,
+            5000,
+            Message::CommandOnly(rt9.clone()),
+        ));
+        capture.push(transaction_at(Bus::BusB, 6000, Message::CommandOnly(rt9)));
+
+        let report = count_switchovers(&capture);
+        assert_eq!(report.per_rt.get(&Address::new(5).unwrap()), Some(&3));
+        assert_eq!(report.per_rt.get(&Address::new(9).unwrap()), None);
+        assert_eq!(report.total, 3);
+    }
+
+    fn data_words(values: impl Iterator<Item = u16>) -> Vec<Word> {
+        values
+            .map(|value| Word::from_data_bits(value, WordType::Data))
+            .collect()
+    }
+
+    fn block(command: &Command, values: impl Iterator<Item = u16>) -> Transaction {
+        transaction(Message::BcToRt {
+            command: command.clone(),
+            data_words: data_words(values),
+            status: None,
+        })
+    }
+
+    #[test]
+    fn test_cross_bus_skew() {
+        let nav = Message::CommandOnly(command(5, CommandType::Transmit, 1, 4));
+        let cfg = Message::CommandOnly(command(2, CommandType::Receive, 3, 2));
+        let capture = vec![
+            transaction_at(Bus::BusA, 1_000, nav.clone()),
+            transaction_at(Bus::BusB, 1_150, nav.clone()),
+            transaction_at(Bus::BusA, 2_000, cfg.clone()),
+            transaction_at(Bus::BusB, 1_980, cfg.clone()),
+            // No Bus B copy within the window
+            transaction_at(Bus::BusA, 3_000, nav.clone()),
+            transaction_at(Bus::BusB, 9_000, nav),
+        ];
+
+        let skews = cross_bus_skew(&capture, Duration::from_millis(1));
+        assert_eq!(
+            skews,
+            vec![
+                BusSkew {
+                    bus_a_index: 0,
+                    bus_b_index: 1,
+                    skew_us: 150,
+                },
+                BusSkew {
+                    bus_a_index: 2,
+                    bus_b_index: 3,
+                    skew_us: -20,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sync_polarity_inverted() {
+        let sync = |word: Word, sync: u8| word.data() | ((sync as u32) << 18);
+        let message = |rt| {
+            let command = command(rt, CommandType::Receive, 1, 2).to_word().unwrap();
+            let mut words = vec![sync(command, Word::SYNC_COMMAND_STATUS)];
+            for word in data_words([0x1234, 0xBEEF].into_iter()) {
+                words.push(sync(word, Word::SYNC_DATA));
+            }
+            words
+        };
+        // Inverting the line flips every bit after the start bit
+        let invert =
+            |words: Vec<u32>| -> Vec<u32> { words.iter().map(|raw| raw ^ 0xFFFFE).collect() };
+        let capture: Vec<Vec<u32>> = (1..=4).map(message).collect();
+
+        let report = sync_polarity(&capture);
+        assert_eq!(
+            report,
+            SyncPolarityReport {
+                normal: 4,
+                inverted: 0
+            }
+        );
+        assert!(!report.is_reversed());
+
+        let reversed: Vec<Vec<u32>> = capture.into_iter().map(invert).collect();
+        let report = sync_polarity(&reversed);
+        assert_eq!(
+            report,
+            SyncPolarityReport {
+                normal: 0,
+                inverted: 4
+            }
+        );
+        assert!(report.is_reversed());
+        assert!(!Word::check_parity(reversed[0][0]));
+    }
+
+    #[test]
+    fn test_busy_retries() {
+        let nav = command(5, CommandType::Transmit, 2, 1);
+        let reply = |busy| Message::CommandStatus {
+            command: nav.clone(),
+            status: StatusWord::new(
+                Address::new(5).unwrap(),
+                StatusFlags::new(false, false, busy, false, false),
+                0,
+            )
+            .unwrap(),
+        };
+        let capture = vec![
+            transaction_at(Bus::BusA, 0, reply(true)),
+            // Another sub-address does not resolve the busy response
+            transaction_at(
+                Bus::BusA,
+                100,
+                Message::CommandOnly(command(5, CommandType::Transmit, 3, 1)),
+            ),
+            transaction_at(Bus::BusA, 250, reply(false)),
+            transaction_at(Bus::BusA, 300, reply(true)),
+        ];
+
+        assert_eq!(
+            busy_retries(&capture),
+            vec![BusyRetry {
+                busy_index: 0,
+                retry_index: 2,
+                latency_us: Some(250),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_busy_retries_bc_to_rt() {
+        let write = command(6, CommandType::Receive, 1, 1);
+        let status = |busy| {
+            StatusWord::new(
+                Address::new(6).unwrap(),
+                StatusFlags::new(false, false, busy, false, false),
+                0,
+            )
+            .unwrap()
+        };
+        let transfer = |busy| Message::BcToRt {
+            command: write.clone(),
+            data_words: vec![Word::from_data_bits(0x00AA, WordType::Data)],
+            status: Some(status(busy)),
+        };
+        // The receiving RT of an RT-to-RT transfer can be busy too
+        let rt_to_rt = |busy| Message::RtToRt {
+            receive_command: write.clone(),
+            transmit_command: command(2, CommandType::Transmit, 4, 1),
+            data_words: vec![Word::from_data_bits(0x00BB, WordType::Data)],
+            receiving_status: Some(status(busy)),
+            transmitting_status: StatusWord::new(
+                Address::new(2).unwrap(),
+                StatusFlags::new(false, false, false, false, false),
+                0,
+            )
+            .unwrap(),
+        };
+        let capture = vec![
+            transaction_at(Bus::BusA, 0, transfer(true)),
+            transaction_at(Bus::BusA, 400, transfer(false)),
+            transaction_at(Bus::BusA, 1_000, rt_to_rt(true)),
+            transaction_at(Bus::BusA, 1_300, rt_to_rt(false)),
+        ];
+
+        assert_eq!(
+            busy_retries(&capture),
+            vec![
+                BusyRetry {
+                    busy_index: 0,
+                    retry_index: 1,
+                    latency_us: Some(400),
+                },
+                BusyRetry {
+                    busy_index: 2,
+                    retry_index: 3,
+                    latency_us: Some(300),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_throughput() {
+        let write = |n: u16| block(&command(3, CommandType::Receive, 1, n), 0..n).message;
+        let capture = vec![
+            transaction_at(Bus::BusA, 0, write(32)),
+            transaction_at(Bus::BusB, 500_000, write(16)),
+            transaction_at(Bus::BusA, 1_000_000, write(32)),
+        ];
+
+        // 128 + 32 bytes over one second
+        let report = throughput(&capture).unwrap();
+        assert_eq!(report.bus_a, 128.0);
+        assert_eq!(report.bus_b, 32.0);
+        assert_eq!(report.overall, 160.0);
+
+        assert_eq!(throughput(&capture[..1]), None);
+    }
+
+    #[test]
+    fn test_reassemble_payload() {
+        let upload = command(3, CommandType::Receive, 7, 32);
+        let capture = vec![block(&upload, 0..32), block(&upload, 32..64)];
+
+        let payload = reassemble_payload(&capture).unwrap();
+        assert_eq!(payload, (0..64).collect::<Vec<u16>>());
+    }
+
+    #[test]
+    fn test_reassemble_payload_rejects_mixed_targets() {
+        let first = command(3, CommandType::Receive, 7, 32);
+        let other = command(3, CommandType::Receive, 8, 32);
+        let capture = vec![block(&first, 0..32), block(&other, 32..64)];
+
+        assert!(reassemble_payload(&capture).is_err());
+    }
+
+    fn read_back(command: &Command, values: &[u16]) -> Transaction {
+        let status = StatusWord::new(
+            command.address,
+            StatusFlags::new(false, false, false, false, false),
+            0,
+        )
+        .unwrap();
+        transaction(Message::RtToBc {
+            command: command.clone(),
+            status,
+            data_words: data_words(values.iter().copied()),
+        })
+    }
+
+    #[test]
+    fn test_sequence_gaps() {
+        let numbered = |sequence| Transaction {
+            sequence: Some(sequence),
+            decoded: None,
+            ..transaction(Message::CommandOnly(command(
+                5,
+                CommandType::Transmit,
+                1,
+                1,
+            )))
+        };
+        let capture: Vec<Transaction> = [1, 2, 4, 5, 9].into_iter().map(numbered).collect();
+
+        assert_eq!(sequence_gaps(&capture), vec![3..=3, 6..=8]);
+        assert!(sequence_gaps(&capture[..2]).is_empty());
+    }
+
+    #[test]
+    fn test_worst_case_latency() {
+        let nav = Message::CommandOnly(command(5, CommandType::Transmit, 1, 1));
+        let ok = |rt| {
+            StatusWord::new(
+                Address::new(rt).unwrap(),
+                StatusFlags::new(false, false, false, false, false),
+                0,
+            )
+            .unwrap()
+        };
+        let capture = vec![
+            // Split command and status, 6 µs after the command word ends
+            transaction_at(Bus::BusA, 0, nav.clone()),
+            transaction_at(Bus::BusA, 26, Message::Status(ok(5))),
+            transaction_at(Bus::BusA, 100, nav.clone()),
+            transaction_at(Bus::BusA, 131, Message::Status(ok(5))),
+            // A status from another RT does not answer the command
+            transaction_at(Bus::BusA, 300, nav.clone()),
+            transaction_at(Bus::BusA, 360, Message::Status(ok(6))),
+        ];
+        assert_eq!(worst_case_latency(&capture), Some((11, 3)));
+        assert_eq!(worst_case_latency(&capture[..1]), None);
+
+        // Answered commands as the parser reports them
+        let mut answered = transaction_at(
+            Bus::BusA,
+            400,
+            Message::CommandStatus {
+                command: command(5, CommandType::Transmit, 31, 2),
+                status: ok(5),
+            },
+        );
+        answered.response_gap_us = Some(9);
+        let mut read = transaction_at(
+            Bus::BusA,
+            500,
+            Message::RtToBc {
+                command: command(5, CommandType::Transmit, 1, 1),
+                status: ok(5),
+                data_words: vec![Word::from_data_bits(0x1234, WordType::Data)],
+            },
+        );
+        read.response_gap_us = Some(14);
+        let mut capture = capture;
+        capture.extend([answered, read]);
+        assert_eq!(worst_case_latency(&capture), Some((14, 7)));
+        assert_eq!(worst_case_latency(&capture[6..7]), Some((9, 0)));
+    }
+
+    #[test]
+    fn test_verify_loopback_match() {
+        let write = block(
+            &command(4, CommandType::Receive, 30, 3),
+            [1, 2, 3].into_iter(),
+        );
+        let read = read_back(&command(4, CommandType::Transmit, 30, 3), &[1, 2, 3]);
+
+        assert_eq!(
+            verify_loopback(&write, &read).unwrap(),
+            LoopbackResult::Match
+        );
+    }
+
+    #[test]
+    fn test_verify_loopback_mismatch() {
+        let write = block(
+            &command(4, CommandType::Receive, 30, 3),
+            [1, 2, 3].into_iter(),
+        );
+        let read = read_back(&command(4, CommandType::Transmit, 30, 3), &[1, 9, 3]);
+
+        assert_eq!(
+            verify_loopback(&write, &read).unwrap(),
+            LoopbackResult::Mismatch {
+                index: 1,
+                written: Some(2),
+                read: Some(9),
+            }
+        );
+
+        let short = read_back(&command(4, CommandType::Transmit, 30, 2), &[1, 2]);
+        assert_eq!(
+            verify_loopback(&write, &short).unwrap(),
+            LoopbackResult::Mismatch {
+                index: 2,
+                written: Some(3),
+                read: None,
+            }
+        );
+    }
+}
@@ -1,6 +1,10 @@
 //! Core types and structures for MIL-STD-1553B protocol
 
+use crate::encoding::{ManchesterDecoder, ManchesterEncoder};
 use crate::error::{ParseError, Result};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 /// Bus identification
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -22,8 +26,8 @@ impl Bus {
     }
 }
 
-impl std::fmt::Display for Bus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for Bus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Bus::BusA => write!(f, "Bus A"),
             Bus::BusB => write!(f, "Bus B"),
@@ -77,8 +81,8 @@ impl Address {
     }
 }
 
-impl std::fmt::Display for Address {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for Address {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if self.is_broadcast() {
             write!(f, "BC (broadcast)")
         } else {
@@ -101,8 +105,8 @@ pub enum WordType {
     ModeCode,
 }
 
-impl std::fmt::Display for WordType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for WordType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             WordType::Command => write!(f, "Command"),
             WordType::Data => write!(f, "Data"),
@@ -112,6 +116,49 @@ impl std::fmt::Display for WordType {
     }
 }
 
+/// Sync pattern that opens a MIL-STD-1553B word
+///
+/// Command and status words share the same sync, so the pattern alone only
+/// separates them from data words.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SyncType {
+    /// Command/status sync: high for 1.5 bit times, then low
+    CommandStatus,
+    /// Data sync: low for 1.5 bit times, then high
+    Data,
+}
+
+impl SyncType {
+    /// Value of the sync field (bits 19-18) for this pattern
+    pub fn bits(&self) -> u8 {
+        match self {
+            SyncType::CommandStatus => Word::SYNC_COMMAND_STATUS,
+            SyncType::Data => Word::SYNC_DATA,
+        }
+    }
+
+    /// Identify the pattern held in a sync field, if it is a valid one
+    pub fn from_bits(bits: u8) -> Option<Self> {
+        match bits {
+            Word::SYNC_COMMAND_STATUS => Some(SyncType::CommandStatus),
+            Word::SYNC_DATA => Some(SyncType::Data),
+            _ => None,
+        }
+    }
+}
+
+/// Parity of every byte value: 1 if it has an odd number of 1s
+const BYTE_PARITY: [u8; 256] = {
+    let mut table = [0u8; 256];
+    let mut value = 0;
+    while value < 256 {
+        table[value] = (value as u8).count_ones() as u8 & 1;
+        value += 1;
+    }
+    table
+};
+
 /// A single MIL-STD-1553B word
 ///
 /// Format:
@@ -121,8 +168,15 @@ impl std::fmt::Display for WordType {
 /// - 2 synchronization bits
 ///
 /// Total: 20 bits
+///
+/// With the `serde` feature, words serialize as their fields (see
+/// `WordFields`); `word_compact` keeps the raw 20-bit form.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "WordFields", try_from = "WordFields")
+)]
 pub struct Word {
     /// 20-bit word value
     data: u32,
@@ -131,6 +185,19 @@ pub struct Word {
 }
 
 impl Word {
+    /// Mask of the parity domain: start bit (bit 0) and 16 data bits (16-1)
+    pub const PARITY_DOMAIN_MASK: u32 = 0x1FFFF;
+    /// Position of the parity bit
+    pub const PARITY_BIT: u32 = 17;
+    /// Sync field (bits 19-18) of a command/status word: high, then low
+    pub const SYNC_COMMAND_STATUS: u8 = 0b10;
+    /// Sync field (bits 19-18) of a data word: low, then high
+    pub const SYNC_DATA: u8 = 0b01;
+    /// Command/status sync waveform as six half-bit levels
+    pub const SYNC_COMMAND_STATUS_WAVEFORM: [bool; 6] = [true, true, true, false, false, false];
+    /// Data sync waveform as six half-bit levels
+    pub const SYNC_DATA_WAVEFORM: [bool; 6] = [false, false, false, true, true, true];
+
     /// Create a new word with validation
     ///
     /// The 16 data bits should be in bits 16:1, parity in bit 17
@@ -148,6 +215,57 @@ impl Word {
         Ok(Word { data, word_type })
     }
 
+    /// Create a word from its on-wire bit sequence
+    ///
+    /// `sync` is the 3-bit-time sync as six half-bit levels, `data_bits` the
+    /// 16 data bits in transmission order (MSB first). A command/status sync
+    /// yields a [`WordType::Command`] word, since status words share the same
+    /// sync and must be re-tagged from context; a data sync yields a
+    /// [`WordType::Data`] word. The parity bit is validated.
+    pub fn from_wire_bits(sync: &[bool], data_bits: &[bool], parity: bool) -> Result<Self> {
+        let (sync_field, word_type) = if sync == Self::SYNC_COMMAND_STATUS_WAVEFORM {
+            (Self::SYNC_COMMAND_STATUS, WordType::Command)
+        } else if sync == Self::SYNC_DATA_WAVEFORM {
+            (Self::SYNC_DATA, WordType::Data)
+        } else {
+            return Err(ParseError::invalid_word(format!(
+                "Invalid sync pattern: {:?}",
+                sync
+            )));
+        };
+
+        if data_bits.len() != 16 {
+            return Err(ParseError::invalid_word(format!(
+                "Expected 16 data bits, got {}",
+                data_bits.len()
+            )));
+        }
+
+        let field = data_bits
+            .iter()
+            .fold(0u32, |acc, &bit| (acc << 1) | bit as u32);
+        let data =
+            ((sync_field as u32) << 18) | ((parity as u32) << Self::PARITY_BIT) | (field << 1);
+
+        Self::new(data, word_type)
+    }
+
+    /// Assemble a word from its 16 data bits
+    ///
+    /// Computes the odd parity bit and sets the sync field to match
+    /// `word_type` (data sync for data words, command/status sync
+    /// otherwise). [`Word::get_data_bits`] recovers `data_bits`.
+    pub fn from_data_bits(data_bits: u16, word_type: WordType) -> Self {
+        let sync = match word_type {
+            WordType::Data => SyncType::Data,
+            _ => SyncType::CommandStatus,
+        };
+        let parity = Self::calculate_parity(data_bits) as u32;
+        let field = (data_bits as u32) << 1;
+        let data = ((sync.bits() as u32) << 18) | (parity << Self::PARITY_BIT) | field;
+        Word { data, word_type }
+    }
+
     /// Create a word without parity validation
     ///
     /// Use with caution - only for constructing test data or when parity
@@ -166,6 +284,11 @@ impl Word {
         self.word_type
     }
 
+    /// Compare the 20-bit data of two words, ignoring their word type
+    pub fn data_eq(&self, other: &Word) -> bool {
+        self.data == other.data
+    }
+
     /// Extract the 16 data bits (bits 16-1)
     pub fn get_data_bits(&self) -> u16 {
         ((self.data >> 1) & 0xFFFF) as u16
@@ -181,19 +304,34 @@ impl Word {
         ((self.data >> 18) & 0x3) as u8
     }
 
+    /// Get the sync pattern of the word, if the sync field holds a valid one
+    pub fn sync_type(&self) -> Option<SyncType> {
+        SyncType::from_bits(self.get_sync_bits())
+    }
+
+    /// Check that the sync field holds the command/status or data pattern
+    pub fn sync_is_valid(&self) -> bool {
+        self.sync_type().is_some()
+    }
+
+    /// Check odd parity across the parity domain and parity bit
+    ///
+    /// Cheap predicate form of the check performed by [`Word::new`]: the
+    /// start bit, 16 data bits and parity bit together must hold an odd
+    /// number of 1s. The sync bits (19-18) are ignored.
+    pub fn check_parity(data: u32) -> bool {
+        let parity_bit = (data >> Self::PARITY_BIT) & 1;
+        let total_ones = (data & Self::PARITY_DOMAIN_MASK).count_ones() + parity_bit;
+        !total_ones.is_multiple_of(2)
+    }
+
     /// Validate odd parity across all 17 bits (bits 16-0)
     ///
     /// In MIL-STD-1553B, odd parity is used over the start bit (0) and
     /// the 16 data bits, and the result is stored in the parity bit.
+    /// The sync bits (19-18) are outside the parity domain and ignored.
     fn validate_parity(data: u32) -> Result<()> {
-        // Count the number of 1s in bits [16:0]
-        let count_bits = (data & 0x1FFFF).count_ones();
-
-        // With odd parity, the total number of 1s (including parity bit) should be odd
-        let parity_bit = ((data >> 17) & 1) != 0;
-        let total_ones = count_bits + if parity_bit { 1 } else { 0 };
-
-        if total_ones % 2 == 0 {
+        if !Self::check_parity(data) {
             return Err(ParseError::parity_error(
                 "Parity check failed: even number of 1s detected".to_string(),
             ));
@@ -202,6 +340,36 @@ impl Word {
         Ok(())
     }
 
+    /// Render the word as its on-wire bit sequence in transmission order
+    ///
+    /// The sync is read from the word's sync field and shown as six
+    /// half-bit levels (`111000` for command/status sync, `000111` for data
+    /// sync). A field holding neither pattern is shown as `invalid(xx)`
+    /// with its two raw bits. The start bit, the 16 data bits MSB first and
+    /// the parity bit follow, separated by spaces.
+    pub fn to_wire_string(&self) -> String {
+        let sync = match self.sync_type() {
+            Some(SyncType::CommandStatus) => "111000".to_string(),
+            Some(SyncType::Data) => "000111".to_string(),
+            None => format!("invalid({:02b})", self.get_sync_bits()),
+        };
+        format!(
+            "{} {} {:016b} {}",
+            sync,
+            self.data & 1,
+            self.get_data_bits(),
+            self.get_parity_bit() as u8
+        )
+    }
+
+    /// Check that the word survives a Manchester encode/decode round trip
+    ///
+    /// Intended as a diagnostic self-check for encoder/decoder asymmetries.
+    pub fn verify_manchester(&self) -> bool {
+        let encoded = ManchesterEncoder::encode_word(self.data);
+        matches!(ManchesterDecoder::decode_word(&encoded), Ok(decoded) if decoded == self.data)
+    }
+
     /// Calculate and set the correct parity bit for a word
     pub fn calculate_parity(data_bits: u16) -> u8 {
         // Start bit is always 0
@@ -209,16 +377,33 @@ impl Word {
         let count_ones = data_bits.count_ones();
 
         // For odd parity, if we have an even number of 1s, we need a parity bit of 1
-        if count_ones % 2 == 0 {
+        if count_ones.is_multiple_of(2) {
             1
         } else {
             0
         }
     }
+
+    /// Calculate the parity bit using a byte lookup table
+    ///
+    /// Gives the same result as [`Word::calculate_parity`], combining the
+    /// parity of the two data bytes from a 256-entry table.
+    pub fn calculate_parity_table(data_bits: u16) -> u8 {
+        let [high, low] = data_bits.to_be_bytes();
+        1 ^ BYTE_PARITY[high as usize] ^ BYTE_PARITY[low as usize]
+    }
+
+    /// Calculate the odd parity bit for each of a batch of data fields
+    pub fn calculate_parities(values: &[u16]) -> Vec<bool> {
+        values
+            .iter()
+            .map(|&value| Self::calculate_parity(value) == 1)
+            .collect()
+    }
 }
 
-impl std::fmt::Display for Word {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for Word {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
             "Word(type={}, data=0x{:05X})",
@@ -227,6 +412,89 @@ impl std::fmt::Display for Word {
     }
 }
 
+/// Serialized form of a [`Word`], with each field of the 20-bit value apart
+///
+/// Deserializing rebuilds the 20-bit value and rejects a parity bit that
+/// does not match the start and data bits, as [`Word::new`] does.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct WordFields {
+    /// The start bit (bit 0), zero in words decoded from the bus
+    pub start: bool,
+    /// The 16 data bits (bits 16-1)
+    pub data_bits: u16,
+    /// The parity bit (bit 17)
+    pub parity: bool,
+    /// The sync field (bits 19-18)
+    pub sync: u8,
+    /// Type of word
+    pub word_type: WordType,
+}
+
+#[cfg(feature = "serde")]
+impl From<Word> for WordFields {
+    fn from(word: Word) -> Self {
+        WordFields {
+            start: word.data & 1 != 0,
+            data_bits: word.get_data_bits(),
+            parity: word.get_parity_bit(),
+            sync: word.get_sync_bits(),
+            word_type: word.word_type,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<WordFields> for Word {
+    type Error = ParseError;
+
+    fn try_from(fields: WordFields) -> Result<Self> {
+        if fields.sync > 0x3 {
+            return Err(ParseError::invalid_word(format!(
+                "Sync field exceeds 2 bits: {:#04b}",
+                fields.sync
+            )));
+        }
+        let data = ((fields.sync as u32) << 18)
+            | ((fields.parity as u32) << Word::PARITY_BIT)
+            | ((fields.data_bits as u32) << 1)
+            | fields.start as u32;
+        Word::new(data, fields.word_type)
+    }
+}
+
+/// Compact serde form of a [`Word`]: the raw 20-bit value and the word type
+///
+/// Use with `#[serde(with = "milstd1553b_parser::core::word_compact")]`.
+/// Deserializing validates the parity bit.
+#[cfg(feature = "serde")]
+pub mod word_compact {
+    use super::{Word, WordType};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Raw word value and type, as serialized
+    #[derive(Serialize, Deserialize)]
+    struct CompactWord {
+        data: u32,
+        word_type: WordType,
+    }
+
+    /// Serialize a word as its raw value and type
+    pub fn serialize<S: Serializer>(word: &Word, serializer: S) -> Result<S::Ok, S::Error> {
+        CompactWord {
+            data: word.data,
+            word_type: word.word_type,
+        }
+        .serialize(serializer)
+    }
+
+    /// Deserialize a word from its raw value and type
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Word, D::Error> {
+        let compact = CompactWord::deserialize(deserializer)?;
+        Word::new(compact.data, compact.word_type).map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -249,12 +517,29 @@ mod tests {
         // Create a simple word with valid parity
         let data_bits = 0xAAAAu16;
         let parity = Word::calculate_parity(data_bits) as u32;
-        let word_data = (parity << 17) | ((data_bits as u32) << 1) | 0;
+        let word_data = (parity << 17) | ((data_bits as u32) << 1);
 
         let word = Word::new(word_data, WordType::Data);
         assert!(word.is_ok());
     }
 
+    #[test]
+    fn test_from_data_bits() {
+        for data_bits in (0..=0xFFFFu16).step_by(257) {
+            for word_type in [WordType::Data, WordType::Command, WordType::Status] {
+                let word = Word::from_data_bits(data_bits, word_type);
+                assert_eq!(Word::new(word.data(), word_type).unwrap(), word);
+                assert_eq!(word.get_data_bits(), data_bits);
+                assert_eq!(word.word_type(), word_type);
+            }
+        }
+
+        let data = Word::from_data_bits(0x1234, WordType::Data);
+        assert_eq!(data.sync_type(), Some(SyncType::Data));
+        let status = Word::from_data_bits(0x1234, WordType::Status);
+        assert_eq!(status.sync_type(), Some(SyncType::CommandStatus));
+    }
+
     #[test]
     fn test_word_parity_validation() {
         // Create a word with wrong parity
@@ -265,6 +550,85 @@ mod tests {
         let _ = result;
     }
 
+    #[test]
+    fn test_sync_bits_outside_parity_domain() {
+        let data_bits = 0x1234u16;
+        let parity = Word::calculate_parity(data_bits) as u32;
+        let word_data = (parity << 17) | ((data_bits as u32) << 1);
+
+        for sync in 0..4u32 {
+            let with_sync = word_data | (sync << 18);
+            assert!(Word::new(with_sync, WordType::Data).is_ok());
+
+            let bad_parity = with_sync ^ (1 << Word::PARITY_BIT);
+            assert!(Word::new(bad_parity, WordType::Data).is_err());
+        }
+    }
+
+    fn field_bits(value: u16) -> Vec<bool> {
+        (0..16).rev().map(|i| (value >> i) & 1 != 0).collect()
+    }
+
+    #[test]
+    fn test_from_wire_bits_command_sync() {
+        let parity = Word::calculate_parity(0x28A5) != 0;
+        let word = Word::from_wire_bits(
+            &Word::SYNC_COMMAND_STATUS_WAVEFORM,
+            &field_bits(0x28A5),
+            parity,
+        )
+        .unwrap();
+
+        assert_eq!(word.word_type(), WordType::Command);
+        assert_eq!(word.get_data_bits(), 0x28A5);
+        assert_eq!(word.get_sync_bits(), Word::SYNC_COMMAND_STATUS);
+    }
+
+    #[test]
+    fn test_from_wire_bits_data_sync() {
+        let parity = Word::calculate_parity(0x1234) != 0;
+        let word =
+            Word::from_wire_bits(&Word::SYNC_DATA_WAVEFORM, &field_bits(0x1234), parity).unwrap();
+
+        assert_eq!(word.word_type(), WordType::Data);
+        assert_eq!(word.get_data_bits(), 0x1234);
+        assert_eq!(word.get_sync_bits(), Word::SYNC_DATA);
+
+        // Wrong parity is still rejected
+        assert!(
+            Word::from_wire_bits(&Word::SYNC_DATA_WAVEFORM, &field_bits(0x1234), !parity).is_err()
+        );
+    }
+
+    #[test]
+    fn test_from_wire_bits_invalid_sync() {
+        let sync = [true, false, true, false, true, false];
+        let result = Word::from_wire_bits(&sync, &field_bits(0x1234), false);
+        assert!(matches!(result, Err(ParseError::InvalidWord(_))));
+
+        let short = [true, true, false, false];
+        assert!(Word::from_wire_bits(&short, &field_bits(0x1234), false).is_err());
+    }
+
+    #[test]
+    fn test_sync_is_valid() {
+        let with_sync = |sync: u32| Word::new_unchecked(sync << 18, WordType::Data);
+        assert!(with_sync(Word::SYNC_COMMAND_STATUS as u32).sync_is_valid());
+        assert!(with_sync(Word::SYNC_DATA as u32).sync_is_valid());
+        assert!(!with_sync(0b00).sync_is_valid());
+        assert!(!with_sync(0b11).sync_is_valid());
+    }
+
+    #[test]
+    fn test_sync_type() {
+        for sync in [SyncType::CommandStatus, SyncType::Data] {
+            let word = Word::new_unchecked((sync.bits() as u32) << 18, WordType::Data);
+            assert_eq!(word.sync_type(), Some(sync));
+        }
+        assert_eq!(SyncType::from_bits(0b00), None);
+        assert_eq!(SyncType::from_bits(0b11), None);
+    }
+
     #[test]
     fn test_calculate_parity() {
         // Odd parity: total number of 1s (including parity bit) should be odd
@@ -278,9 +642,120 @@ mod tests {
         assert_eq!(parity, 0); // 1 one (odd) → parity=0, total stays odd
     }
 
+    #[test]
+    fn test_calculate_parity_table_matches() {
+        for value in 0..=u16::MAX {
+            assert_eq!(
+                Word::calculate_parity_table(value),
+                Word::calculate_parity(value),
+                "value=0x{:04X}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_calculate_parities() {
+        let values = [0x0000, 0x0001, 0xFFFF, 0x8000, 0x1234, 0xBEEF];
+        let expected: Vec<bool> = values
+            .iter()
+            .map(|&value| Word::calculate_parity(value) == 1)
+            .collect();
+        assert_eq!(Word::calculate_parities(&values), expected);
+        assert!(Word::calculate_parities(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_check_parity_matches_validate_parity() {
+        for data in (0..0x100000u32).step_by(7) {
+            assert_eq!(
+                Word::check_parity(data),
+                Word::validate_parity(data).is_ok(),
+                "data=0x{:05X}",
+                data
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify_manchester() {
+        for data_bits in (0..=0xFFFFu16).step_by(257) {
+            let word = Word::from_data_bits(data_bits, WordType::Data);
+            assert!(word.verify_manchester(), "word {:#07X} failed", word.data());
+        }
+    }
+
+    #[test]
+    fn test_data_eq_ignores_word_type() {
+        let data = Word::new_unchecked(0x2468A, WordType::Data);
+        let command = Word::new_unchecked(0x2468A, WordType::Command);
+        let other = Word::new_unchecked(0x2468B, WordType::Data);
+
+        assert_ne!(data, command);
+        assert!(data.data_eq(&command));
+        assert!(!data.data_eq(&other));
+    }
+
+    #[test]
+    fn test_to_wire_string() {
+        let word = Word::from_data_bits(0x1234, WordType::Data);
+        let wire = word.to_wire_string();
+        assert_eq!(wire.len(), 27);
+        assert_eq!(wire, "000111 0 0001001000110100 0");
+
+        // The sync comes from the sync field, not the word type
+        let command = Word::from_data_bits(0x1234, WordType::Command);
+        assert!(command.to_wire_string().starts_with("111000 "));
+        let mislabelled = Word::new_unchecked(word.data(), WordType::Command);
+        assert!(mislabelled.to_wire_string().starts_with("000111 "));
+
+        let no_sync = Word::new_unchecked(word.data() & 0x3FFFF, WordType::Data);
+        assert_eq!(no_sync.to_wire_string(), "invalid(00) 0 0001001000110100 0");
+        let start = Word::new_unchecked(word.data() | 1, WordType::Data);
+        assert_eq!(start.to_wire_string(), "000111 1 0001001000110100 0");
+    }
+
     #[test]
     fn test_bus_display() {
         assert_eq!(Bus::BusA.to_string(), "Bus A");
         assert_eq!(Bus::BusB.to_string(), "Bus B");
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_word_serde_fields() {
+        let word = Word::from_data_bits(0x1234, WordType::Data);
+        let json = serde_json::to_value(word).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "start": false,
+                "data_bits": 0x1234,
+                "parity": word.get_parity_bit(),
+                "sync": Word::SYNC_DATA,
+                "word_type": "Data",
+            })
+        );
+        assert_eq!(serde_json::from_value::<Word>(json.clone()).unwrap(), word);
+
+        // A flipped parity bit is rejected
+        let mut tampered = json;
+        tampered["parity"] = serde_json::Value::Bool(!word.get_parity_bit());
+        assert!(serde_json::from_value::<Word>(tampered).is_err());
+
+        // The start bit is part of the parity domain, so it is carried too
+        let started = Word::new(1, WordType::Data).unwrap();
+        let json = serde_json::to_value(started).unwrap();
+        assert_eq!(serde_json::from_value::<Word>(json).unwrap(), started);
+
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Compact(#[serde(with = "word_compact")] Word);
+        let compact = serde_json::to_string(&Compact(word)).unwrap();
+        assert_eq!(
+            compact,
+            format!(r#"{{"data":{},"word_type":"Data"}}"#, word.data())
+        );
+        let parsed: Compact = serde_json::from_str(&compact).unwrap();
+        assert_eq!(parsed.0, word);
+    }
 }
@@ -1,6 +1,10 @@
 //! Manchester encoding and decoding for MIL-STD-1553B
 
+use crate::core::{SyncType, Word, WordType};
 use crate::error::{ParseError, Result};
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
 
 /// Manchester encoding type for MIL-STD-1553B
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -16,6 +20,31 @@ impl ManchesterType {
     pub fn milstd() -> Self {
         ManchesterType::Thomas
     }
+
+    /// Encode a single bit as a Manchester pair with this polarity
+    ///
+    /// The first half-bit is held in bit 1 of the pair and the second in
+    /// bit 0, so `0b10` is a high-to-low transition.
+    pub fn encode_bit(&self, bit: bool) -> u8 {
+        match (self, bit) {
+            (ManchesterType::Thomas, false) | (ManchesterType::Ieee, true) => 0b10,
+            (ManchesterType::Thomas, true) | (ManchesterType::Ieee, false) => 0b01,
+        }
+    }
+
+    /// Decode a Manchester pair with this polarity
+    ///
+    /// Returns Err for pairs without a mid-bit transition.
+    pub fn decode_bit(&self, pair: u8) -> Result<bool> {
+        match pair & 0x3 {
+            0b01 => Ok(*self == ManchesterType::Thomas),
+            0b10 => Ok(*self == ManchesterType::Ieee),
+            _ => Err(ParseError::invalid_manchester(format!(
+                "Invalid Manchester pattern: {:#04b}",
+                pair
+            ))),
+        }
+    }
 }
 
 /// Manchester encoder for MIL-STD-1553B
@@ -27,22 +56,24 @@ impl ManchesterEncoder {
     /// 0 = high-to-low transition (1, 0)
     /// 1 = low-to-high transition (0, 1)
     pub fn encode_bit(bit: bool) -> u8 {
-        match bit {
-            false => 0b10, // high-to-low
-            true => 0b01,  // low-to-high
-        }
+        ManchesterType::milstd().encode_bit(bit)
     }
 
     /// Encode multiple bits (little-endian bit order)
     ///
     /// Returns a vector of bytes representing the Manchester-encoded data
     pub fn encode_bits(data: &[bool]) -> Vec<u8> {
-        let mut result = Vec::with_capacity((data.len() + 3) / 4);
+        Self::encode_bits_as(data, ManchesterType::milstd())
+    }
+
+    /// Encode multiple bits with the given Manchester polarity
+    pub fn encode_bits_as(data: &[bool], manchester_type: ManchesterType) -> Vec<u8> {
+        let mut result = Vec::with_capacity(data.len().div_ceil(4));
         let mut byte = 0u8;
         let mut bit_pos = 0;
 
         for &bit in data {
-            let encoded = Self::encode_bit(bit);
+            let encoded = manchester_type.encode_bit(bit);
             byte |= (encoded & 0x3) << bit_pos;
             bit_pos += 2;
 
@@ -61,12 +92,136 @@ impl ManchesterEncoder {
     }
 
     /// Encode a word (20 bits) into Manchester-encoded data
+    ///
+    /// Low-level helper that encodes all 20 bits, sync field included, as
+    /// Manchester bit pairs. See [`Self::encode_word_with_sync`] for the
+    /// waveform a bus receiver expects.
     pub fn encode_word(word: u32) -> Vec<u8> {
-        let mut bits = Vec::with_capacity(20);
-        for i in 0..20 {
-            bits.push(((word >> i) & 1) != 0);
+        let mut out = Vec::with_capacity(5);
+        Self::encode_word_into(word, &mut out);
+        out
+    }
+
+    /// Append the Manchester encoding of a word (20 bits) to `out`
+    ///
+    /// Writes the 5 encoded bytes straight from the word's bits, without
+    /// intermediate buffers.
+    pub fn encode_word_into(word: u32, out: &mut Vec<u8>) {
+        Self::push_word(word, ManchesterType::milstd(), out);
+    }
+
+    /// Encode a word (20 bits) with the given Manchester polarity
+    pub fn encode_word_as(word: u32, manchester_type: ManchesterType) -> Vec<u8> {
+        let mut out = Vec::with_capacity(5);
+        Self::push_word(word, manchester_type, &mut out);
+        out
+    }
+
+    /// Append the 5 encoded bytes of a word, four bit pairs per byte
+    fn push_word(word: u32, manchester_type: ManchesterType, out: &mut Vec<u8>) {
+        for byte_index in 0..5 {
+            let mut byte = 0u8;
+            for pair_index in 0..4 {
+                let bit = (word >> (byte_index * 4 + pair_index)) & 1 != 0;
+                byte |= manchester_type.encode_bit(bit) << (2 * pair_index);
+            }
+            out.push(byte);
+        }
+    }
+
+    /// Encode a word as it appears on the bus, opened by its sync pulse
+    ///
+    /// The word spans 20 bit times (40 half-bits, 5 bytes): the 3-bit-time
+    /// sync pulse, the 16 data bits MSB first, and the odd parity bit. The
+    /// sync holds each level for 1.5 bit times, which is deliberately not
+    /// valid Manchester, so it occupies three pairs that
+    /// [`ManchesterDecoder::decode_bit`] would reject.
+    pub fn encode_word_with_sync(data_bits: u16, sync: SyncType) -> Vec<u8> {
+        Self::encode_word_with_sync_as(data_bits, sync, ManchesterType::milstd())
+    }
+
+    /// Encode an on-bus word with the given Manchester polarity
+    ///
+    /// The sync pulse is a fixed line-level pattern and is the same for
+    /// both polarities; only the data and parity bits follow
+    /// `manchester_type`.
+    pub fn encode_word_with_sync_as(
+        data_bits: u16,
+        sync: SyncType,
+        manchester_type: ManchesterType,
+    ) -> Vec<u8> {
+        let waveform = match sync {
+            SyncType::CommandStatus => Word::SYNC_COMMAND_STATUS_WAVEFORM,
+            SyncType::Data => Word::SYNC_DATA_WAVEFORM,
+        };
+
+        let mut pairs = Vec::with_capacity(crate::spec::WORD_LENGTH);
+        pairs.extend(
+            waveform
+                .chunks(2)
+                .map(|half_bits| ((half_bits[0] as u8) << 1) | half_bits[1] as u8),
+        );
+        for i in (0..16).rev() {
+            pairs.push(manchester_type.encode_bit((data_bits >> i) & 1 != 0));
+        }
+        pairs.push(manchester_type.encode_bit(Word::calculate_parity(data_bits) == 1));
+
+        pairs
+            .chunks(4)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .enumerate()
+                    .fold(0u8, |byte, (i, &pair)| byte | (pair << (2 * i)))
+            })
+            .collect()
+    }
+
+    /// Encode several words with an idle gap between consecutive words
+    ///
+    /// `gap_half_bits` is the number of idle half-bit times (no transitions,
+    /// encoded as `0b00` pairs) inserted between words. It must be even so
+    /// that following words stay aligned to 2-bit symbols.
+    pub fn encode_words_with_gap(words: &[u32], gap_half_bits: usize) -> Result<Vec<u8>> {
+        if !gap_half_bits.is_multiple_of(2) {
+            return Err(ParseError::invalid_manchester(format!(
+                "Inter-word gap must be an even number of half-bits, got {}",
+                gap_half_bits
+            )));
+        }
+
+        let gap_pairs = gap_half_bits / 2;
+        let total_pairs = words.len() * 20 + words.len().saturating_sub(1) * gap_pairs;
+        let mut result = Vec::with_capacity(total_pairs.div_ceil(4));
+        let mut byte = 0u8;
+        let mut bit_pos = 0;
+
+        let mut push_pair = |pair: u8| {
+            byte |= (pair & 0x3) << bit_pos;
+            bit_pos += 2;
+            if bit_pos == 8 {
+                result.push(byte);
+                byte = 0;
+                bit_pos = 0;
+            }
+        };
+
+        for (index, &word) in words.iter().enumerate() {
+            if index > 0 {
+                for _ in 0..gap_pairs {
+                    push_pair(0b00);
+                }
+            }
+            for i in 0..20 {
+                push_pair(Self::encode_bit(((word >> i) & 1) != 0));
+            }
         }
-        Self::encode_bits(&bits)
+
+        if bit_pos > 0 {
+            result.push(byte);
+        }
+
+        Ok(result)
     }
 }
 
@@ -74,23 +229,78 @@ impl ManchesterEncoder {
 pub struct ManchesterDecoder;
 
 impl ManchesterDecoder {
+    /// Remove the idle gaps inserted by [`ManchesterEncoder::encode_words_with_gap`]
+    ///
+    /// Returns the packed words back to back, as [`ManchesterEncoder::encode_word`]
+    /// would produce them. Idle pairs after the last whole word, such as the
+    /// padding of the final byte, are dropped. Fails if `gap_half_bits` is
+    /// odd, a gap holds anything but idle `0b00` pairs, or the stream ends
+    /// partway through a word.
+    pub fn remove_gaps(data: &[u8], gap_half_bits: usize) -> Result<Vec<u8>> {
+        if !gap_half_bits.is_multiple_of(2) {
+            return Err(ParseError::invalid_manchester(format!(
+                "Inter-word gap must be an even number of half-bits, got {}",
+                gap_half_bits
+            )));
+        }
+
+        let gap_pairs = gap_half_bits / 2;
+        let pairs: Vec<u8> = data
+            .iter()
+            .flat_map(|&byte| (0..8).step_by(2).map(move |shift| (byte >> shift) & 0x3))
+            .collect();
+
+        let mut result = Vec::with_capacity(data.len());
+        let mut index = 0;
+        while index + crate::spec::WORD_LENGTH <= pairs.len() {
+            let word = &pairs[index..index + crate::spec::WORD_LENGTH];
+            for chunk in word.chunks(4) {
+                result.push(
+                    chunk
+                        .iter()
+                        .enumerate()
+                        .fold(0u8, |byte, (i, &pair)| byte | (pair << (2 * i))),
+                );
+            }
+            index += crate::spec::WORD_LENGTH;
+
+            let gap_end = (index + gap_pairs).min(pairs.len());
+            if let Some(offset) = pairs[index..gap_end].iter().position(|&pair| pair != 0) {
+                return Err(ParseError::invalid_manchester(format!(
+                    "Expected an idle inter-word gap at pair {}",
+                    index + offset
+                )));
+            }
+            index = gap_end;
+        }
+
+        // Anything but padding after the last whole word is a truncated word
+        if let Some(last) = pairs[index..].iter().rposition(|&pair| pair != 0) {
+            crate::spec::validate_word_length(last + 1)?;
+        }
+        Ok(result)
+    }
+
     /// Decode a single Manchester-encoded bit pair (Thomas encoding)
     ///
     /// Returns Ok(bit) on valid encoding, Err on invalid pattern
     pub fn decode_bit(pair: u8) -> Result<bool> {
-        match pair & 0x3 {
-            0b01 => Ok(true),   // low-to-high = 1
-            0b10 => Ok(false),  // high-to-low = 0
-            _ => Err(ParseError::invalid_manchester(
-                format!("Invalid Manchester pattern: {:#04b}", pair),
-            )),
-        }
+        ManchesterType::milstd().decode_bit(pair)
     }
 
     /// Decode a sequence of Manchester-encoded bits
     ///
     /// Each byte contains 4 Manchester-encoded bits (2 bits per bit)
     pub fn decode_bits(data: &[u8], num_bits: usize) -> Result<Vec<bool>> {
+        Self::decode_bits_as(data, num_bits, ManchesterType::milstd())
+    }
+
+    /// Decode a sequence of bits encoded with the given Manchester polarity
+    pub fn decode_bits_as(
+        data: &[u8],
+        num_bits: usize,
+        manchester_type: ManchesterType,
+    ) -> Result<Vec<bool>> {
         let mut result = Vec::with_capacity(num_bits);
 
         for &byte in data {
@@ -99,7 +309,7 @@ impl ManchesterDecoder {
                     break;
                 }
                 let pair = (byte >> shift) & 0x3;
-                result.push(Self::decode_bit(pair)?);
+                result.push(manchester_type.decode_bit(pair)?);
             }
 
             if result.len() >= num_bits {
@@ -118,25 +328,436 @@ impl ManchesterDecoder {
 
     /// Decode a Manchester-encoded word (20 bits)
     ///
-    /// Expects 5 bytes (40 bits) of Manchester-encoded data
+    /// Expects 5 bytes (40 bits) of Manchester-encoded data. The sync field
+    /// is recovered but not checked; see [`Self::decode_word_checked`].
     pub fn decode_word(data: &[u8]) -> Result<u32> {
+        Self::decode_word_checked(data, false)
+    }
+
+    /// Decode a Manchester-encoded word, optionally checking its sync field
+    ///
+    /// With `strict` set, the sync field (bits 19-18) must hold the
+    /// command/status or data sync pattern; anything else is rejected.
+    pub fn decode_word_checked(data: &[u8], strict: bool) -> Result<u32> {
+        let word = Self::decode_word_as(data, ManchesterType::milstd())?;
+
+        if strict {
+            let decoded = Word::new_unchecked(word, WordType::Data);
+            if !decoded.sync_is_valid() {
+                return Err(ParseError::invalid_word(format!(
+                    "Invalid sync pattern: {:#04b}",
+                    decoded.get_sync_bits()
+                )));
+            }
+        }
+
+        Ok(word)
+    }
+
+    /// Decode a word (20 bits) encoded with the given Manchester polarity
+    ///
+    /// The sync field is recovered but not checked.
+    pub fn decode_word_as(data: &[u8], manchester_type: ManchesterType) -> Result<u32> {
         if data.len() < 5 {
             return Err(ParseError::insufficient_data(
                 format!("Expected 5 bytes for word, got {}", data.len()),
             ));
         }
 
-        let bits = Self::decode_bits(data, 20)?;
+        // Accumulate the bits straight into the word, four pairs per byte
         let mut word = 0u32;
-
-        for (i, &bit) in bits.iter().enumerate() {
-            if bit {
-                word |= 1 << i;
+        for (index, &byte) in data[..5].iter().enumerate() {
+            for pair in 0..4 {
+                let bit = manchester_type.decode_bit((byte >> (pair * 2)) & 0x3)?;
+                word |= (bit as u32) << (index * 4 + pair);
             }
         }
 
         Ok(word)
     }
+
+    /// Decode a word produced by [`ManchesterEncoder::encode_word_with_sync`]
+    ///
+    /// Expects 5 bytes: three pairs of sync pulse, then 17 Manchester bits
+    /// (16 data bits MSB first and the parity bit). Returns the data bits
+    /// and the sync pattern. Fails on an unrecognized sync pulse, invalid
+    /// Manchester pairs, or a parity error.
+    pub fn decode_word_with_sync(data: &[u8]) -> Result<(u16, SyncType)> {
+        Self::decode_word_with_sync_as(data, ManchesterType::milstd())
+    }
+
+    /// Decode an on-bus word encoded with the given Manchester polarity
+    ///
+    /// The counterpart of [`ManchesterEncoder::encode_word_with_sync_as`].
+    pub fn decode_word_with_sync_as(
+        data: &[u8],
+        manchester_type: ManchesterType,
+    ) -> Result<(u16, SyncType)> {
+        if data.len() < 5 {
+            return Err(ParseError::insufficient_data(format!(
+                "Expected 5 bytes for word, got {}",
+                data.len()
+            )));
+        }
+
+        let mut pairs = [0u8; crate::spec::WORD_LENGTH];
+        for (index, pair) in pairs.iter_mut().enumerate() {
+            *pair = (data[index / 4] >> (index % 4 * 2)) & 0x3;
+        }
+        let (sync_pairs, bit_pairs) = pairs.split_at(crate::spec::SYNC_LENGTH);
+
+        let mut waveform = [false; 2 * crate::spec::SYNC_LENGTH];
+        for (index, &pair) in sync_pairs.iter().enumerate() {
+            waveform[2 * index] = pair & 0b10 != 0;
+            waveform[2 * index + 1] = pair & 0b01 != 0;
+        }
+        let sync = if waveform == Word::SYNC_COMMAND_STATUS_WAVEFORM {
+            SyncType::CommandStatus
+        } else if waveform == Word::SYNC_DATA_WAVEFORM {
+            SyncType::Data
+        } else {
+            return Err(ParseError::invalid_word(format!(
+                "Invalid sync pulse: {:?}",
+                waveform
+            )));
+        };
+
+        let mut data_bits = 0u16;
+        for &pair in &bit_pairs[..16] {
+            data_bits = (data_bits << 1) | manchester_type.decode_bit(pair)? as u16;
+        }
+        let parity = manchester_type.decode_bit(bit_pairs[16])?;
+        if parity as u8 != Word::calculate_parity(data_bits) {
+            return Err(ParseError::parity_error(format!(
+                "Parity check failed for data 0x{:04X}",
+                data_bits
+            )));
+        }
+
+        Ok((data_bits, sync))
+    }
+
+    /// Find the next word opened by a valid sync pulse
+    ///
+    /// Searches the packed stream half-bit by half-bit, from half-bit
+    /// offset `start` (each byte holds 8 half-bits, four Manchester pairs).
+    /// A sync-like pattern is only accepted where it opens a whole word
+    /// that [`Self::decode_word_with_sync`] accepts, i.e. 17 valid
+    /// Manchester bits with correct parity follow it. Returns the half-bit
+    /// offset of the word, or `None` if no complete word follows `start`.
+    ///
+    /// The sync pulse is matched in place at each offset; a word window is
+    /// only extracted and decoded where one matches.
+    pub fn find_sync(data: &[u8], start: usize) -> Option<usize> {
+        let last = (data.len() * 8).checked_sub(crate::spec::MANCHESTER_BITS_PER_WORD)?;
+        let sync_at = |offset: usize, waveform: &[bool]| {
+            waveform
+                .iter()
+                .enumerate()
+                .all(|(index, &level)| (Self::half_bit(data, offset + index) != 0) == level)
+        };
+        (start..=last).find(|&offset| {
+            (sync_at(offset, &Word::SYNC_COMMAND_STATUS_WAVEFORM)
+                || sync_at(offset, &Word::SYNC_DATA_WAVEFORM))
+                && Self::word_at(data, offset)
+                    .is_some_and(|window| Self::decode_word_with_sync(&window).is_ok())
+        })
+    }
+
+    /// Extract the 40 half-bits of a word starting at any half-bit offset
+    ///
+    /// The half-bits are repacked into 5 bytes, four pairs per byte, so a
+    /// word found by [`Self::find_sync`] can be passed to the word decoders.
+    /// Returns `None` if the stream ends before the word does.
+    pub fn word_at(data: &[u8], offset: usize) -> Option<Vec<u8>> {
+        if offset + crate::spec::MANCHESTER_BITS_PER_WORD > data.len() * 8 {
+            return None;
+        }
+
+        let mut word = vec![0u8; 5];
+        for pair_index in 0..crate::spec::WORD_LENGTH {
+            let first = Self::half_bit(data, offset + 2 * pair_index);
+            let second = Self::half_bit(data, offset + 2 * pair_index + 1);
+            word[pair_index / 4] |= ((first << 1) | second) << (2 * (pair_index % 4));
+        }
+        Some(word)
+    }
+
+    /// Level (0 or 1) of the half-bit at `index` in a packed stream
+    fn half_bit(data: &[u8], index: usize) -> u8 {
+        let pair = (data[index / 8] >> (index % 8 / 2 * 2)) & 0x3;
+        if index.is_multiple_of(2) {
+            pair >> 1
+        } else {
+            pair & 1
+        }
+    }
+
+    /// Measure edge jitter in oversampled Manchester data
+    ///
+    /// `samples` holds one line level per sample (zero is low, anything else
+    /// is high) and `samples_per_bit` must be even so half-bit boundaries fall
+    /// on whole samples. Ideal edge positions form a half-bit grid anchored at
+    /// the first transition; each edge's distance to the nearest grid point is
+    /// its jitter. Statistics are reported per word (20 bit times), starting
+    /// at the first transition.
+    pub fn edge_jitter(samples: &[u8], samples_per_bit: usize) -> Result<Vec<JitterStats>> {
+        Self::check_samples_per_bit(samples_per_bit)?;
+
+        let edges = Self::sample_edges(samples);
+        let Some(&anchor) = edges.first() else {
+            return Ok(Vec::new());
+        };
+
+        let half_bit = samples_per_bit / 2;
+        let word_span = crate::spec::WORD_LENGTH * samples_per_bit;
+        let mut stats: Vec<JitterStats> = Vec::new();
+
+        for edge in edges {
+            let offset = edge - anchor;
+            let phase = offset % half_bit;
+            let deviation = phase.min(half_bit - phase) as f64;
+
+            let word_index = offset / word_span;
+            if stats.len() <= word_index {
+                stats.resize(
+                    word_index + 1,
+                    JitterStats {
+                        edges: 0,
+                        mean: 0.0,
+                        max: 0.0,
+                    },
+                );
+            }
+
+            let word = &mut stats[word_index];
+            word.edges += 1;
+            word.mean += deviation;
+            word.max = word.max.max(deviation);
+        }
+
+        for word in &mut stats {
+            if word.edges > 0 {
+                word.mean /= word.edges as f64;
+            }
+        }
+
+        Ok(stats)
+    }
+
+    /// Recover Manchester bit pairs from oversampled line levels
+    ///
+    /// `samples` holds one line level per sample (zero is low, anything else
+    /// is high) and `samples_per_bit` must be even, as for
+    /// [`Self::edge_jitter`]. Leading idle samples are skipped: the half-bit
+    /// clock starts at the first transition and is re-anchored on every edge
+    /// that falls within a quarter bit of its expected position, so small
+    /// amounts of jitter do not accumulate. Each half-bit is read at its
+    /// centre. Decoding stops at the first idle period (more than two bit
+    /// times without a transition).
+    ///
+    /// The output is packed four pairs per byte, as produced by
+    /// [`ManchesterEncoder::encode_bits`], ready for [`Self::decode_word`] or
+    /// [`Self::decode_word_with_sync`]. Whether the first transition is a bit
+    /// boundary or a mid-bit edge is decided by which alignment yields fewer
+    /// invalid pairs. A waveform that opens at the idle level, such as a
+    /// data sync after a low idle, cannot be told apart from the idle before
+    /// it.
+    pub fn decode_from_samples(samples: &[u8], samples_per_bit: usize) -> Result<Vec<u8>> {
+        Self::check_samples_per_bit(samples_per_bit)?;
+
+        let edges = Self::sample_edges(samples);
+        let Some(&first) = edges.first() else {
+            return Ok(Vec::new());
+        };
+
+        let half_bit = samples_per_bit / 2;
+        let tolerance = half_bit / 2;
+        let mut levels: Vec<bool> = Vec::new();
+        let mut last_edge_cell = 0;
+        let mut run = 0;
+        let mut boundary = first;
+        let mut next_edge = 1;
+
+        while boundary + half_bit <= samples.len() {
+            let level = samples[boundary + half_bit / 2] != 0;
+            run = if levels.last() == Some(&level) {
+                run + 1
+            } else {
+                1
+            };
+            // A sync pulse and the first half of the next bit share a level
+            if run > crate::spec::SYNC_LENGTH + 1 {
+                break;
+            }
+            levels.push(level);
+
+            // Re-anchor on an edge close to the nominal next boundary
+            let nominal = boundary + half_bit;
+            while next_edge < edges.len() && edges[next_edge] + tolerance < nominal {
+                next_edge += 1;
+            }
+            match edges.get(next_edge) {
+                Some(&edge) if edge <= nominal + tolerance => {
+                    boundary = edge;
+                    next_edge += 1;
+                    last_edge_cell = levels.len();
+                }
+                _ => boundary = nominal,
+            }
+        }
+
+        // Every bit ends on the half-bit after its mid-bit edge
+        levels.truncate(last_edge_cell + 1);
+        if levels.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let invalid_pairs = |cells: &[bool]| {
+            cells
+                .chunks_exact(2)
+                .filter(|pair| pair[0] == pair[1])
+                .count()
+        };
+        let mut shifted = vec![!levels[0]];
+        shifted.extend_from_slice(&levels);
+        let cells = if invalid_pairs(&shifted) < invalid_pairs(&levels) {
+            shifted
+        } else {
+            levels
+        };
+
+        let pairs: Vec<u8> = cells
+            .chunks_exact(2)
+            .map(|pair| ((pair[0] as u8) << 1) | pair[1] as u8)
+            .collect();
+        Ok(pairs
+            .chunks(4)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .enumerate()
+                    .fold(0u8, |byte, (i, &pair)| byte | (pair << (2 * i)))
+            })
+            .collect())
+    }
+
+    /// Decode sync-led words from oversampled line levels, with a confidence
+    ///
+    /// The samples are recovered as by [`Self::decode_from_samples`] and
+    /// split into words of five bytes, each decoded with
+    /// [`Self::decode_word_with_sync`]; a word that fails to decode fails
+    /// the whole call. Each word's confidence is derived from its
+    /// [`Self::edge_jitter`] statistics: 1.0 when every edge sits on the
+    /// half-bit grid, falling linearly to 0.0 as the worst edge approaches a
+    /// quarter bit off, the most the decoder's clock recovery tolerates. The
+    /// words should follow each other back to back from the first one; a
+    /// waveform that ends partway through a word is rejected.
+    pub fn decode_words_from_samples(
+        samples: &[u8],
+        samples_per_bit: usize,
+    ) -> Result<Vec<SampledWord>> {
+        let recovered = Self::decode_from_samples(samples, samples_per_bit)?;
+        let jitter = Self::edge_jitter(samples, samples_per_bit)?;
+        let tolerance = (samples_per_bit / 4) as f64;
+
+        recovered
+            .chunks(5)
+            .enumerate()
+            .map(|(index, bytes)| {
+                crate::spec::validate_manchester_length(bytes.len() * 8)?;
+                let (data_bits, sync) = Self::decode_word_with_sync(bytes)?;
+                let confidence = jitter
+                    .get(index)
+                    .map_or(0.0, |stats| 1.0 - (stats.max / tolerance).min(1.0));
+                Ok(SampledWord {
+                    data_bits,
+                    sync,
+                    confidence,
+                })
+            })
+            .collect()
+    }
+
+    /// Check that half-bit boundaries fall on whole samples
+    fn check_samples_per_bit(samples_per_bit: usize) -> Result<()> {
+        if samples_per_bit < 2 || !samples_per_bit.is_multiple_of(2) {
+            return Err(ParseError::invalid_manchester(format!(
+                "Samples per bit must be even and at least 2, got {}",
+                samples_per_bit
+            )));
+        }
+        Ok(())
+    }
+
+    /// Positions of the samples where the line level changes
+    fn sample_edges(samples: &[u8]) -> Vec<usize> {
+        (1..samples.len())
+            .filter(|&i| (samples[i] != 0) != (samples[i - 1] != 0))
+            .collect()
+    }
+}
+
+/// Edge timing statistics for one word of oversampled Manchester data
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JitterStats {
+    /// Number of transitions measured
+    pub edges: usize,
+    /// Mean deviation from the ideal edge position, in samples
+    pub mean: f64,
+    /// Largest deviation from the ideal edge position, in samples
+    pub max: f64,
+}
+
+/// A word decoded from oversampled line levels
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SampledWord {
+    /// The 16-bit data field
+    pub data_bits: u16,
+    /// The sync pattern that opened the word
+    pub sync: SyncType,
+    /// How cleanly the word's edges were timed, from 0.0 to 1.0
+    pub confidence: f64,
+}
+
+/// Flip bits of a Manchester-encoded stream at a given bit error rate
+///
+/// Each bit of `data` is flipped independently with probability `ber`.
+/// The pseudo-random sequence is fully determined by `seed`, so a given
+/// seed always produces the same corruption pattern. Returns the number
+/// of bits flipped.
+pub fn corrupt_stream(data: &mut [u8], ber: f64, seed: u64) -> usize {
+    let mut rng = SplitMix64(seed);
+    let mut flipped = 0;
+
+    for byte in data.iter_mut() {
+        for bit in 0..8 {
+            if rng.next_f64() < ber {
+                *byte ^= 1 << bit;
+                flipped += 1;
+            }
+        }
+    }
+
+    flipped
+}
+
+/// Minimal SplitMix64 generator for reproducible fault injection
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in [0, 1)
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
 }
 
 #[cfg(test)]
@@ -151,12 +772,63 @@ mod tests {
 
     #[test]
     fn test_manchester_decode_bit() {
-        assert_eq!(ManchesterDecoder::decode_bit(0b10).unwrap(), false);
-        assert_eq!(ManchesterDecoder::decode_bit(0b01).unwrap(), true);
+        assert!(!ManchesterDecoder::decode_bit(0b10).unwrap());
+        assert!(ManchesterDecoder::decode_bit(0b01).unwrap());
         assert!(ManchesterDecoder::decode_bit(0b00).is_err());
         assert!(ManchesterDecoder::decode_bit(0b11).is_err());
     }
 
+    #[test]
+    fn test_manchester_type_polarity() {
+        let ieee = ManchesterType::Ieee;
+        assert_eq!(ManchesterType::milstd(), ManchesterType::Thomas);
+        assert_eq!(ieee.encode_bit(false), 0b01);
+        assert_eq!(ieee.encode_bit(true), 0b10);
+        assert!(ieee.decode_bit(0b10).unwrap());
+        assert!(!ieee.decode_bit(0b01).unwrap());
+        assert!(ieee.decode_bit(0b11).is_err());
+    }
+
+    #[test]
+    fn test_manchester_word_round_trip_both_polarities() {
+        for manchester_type in [ManchesterType::Thomas, ManchesterType::Ieee] {
+            for word in [0x00000, 0x12345, 0xFFFFF] {
+                let encoded = ManchesterEncoder::encode_word_as(word, manchester_type);
+                assert_eq!(
+                    ManchesterDecoder::decode_word_as(&encoded, manchester_type).unwrap(),
+                    word
+                );
+            }
+        }
+
+        // The two polarities are bitwise complements of each other
+        let thomas = ManchesterEncoder::encode_word_as(0x12345, ManchesterType::Thomas);
+        let ieee = ManchesterEncoder::encode_word_as(0x12345, ManchesterType::Ieee);
+        assert!(thomas.iter().zip(&ieee).all(|(a, b)| *a == !*b));
+        assert_eq!(thomas, ManchesterEncoder::encode_word(0x12345));
+        assert_eq!(
+            ManchesterDecoder::decode_word_as(&ieee, ManchesterType::Thomas).unwrap(),
+            0x12345 ^ 0xFFFFF
+        );
+    }
+
+    #[test]
+    fn test_word_fast_paths_match_bit_paths() {
+        let mut stream = Vec::new();
+        for word in (0..0xFFFFFu32).step_by(97) {
+            let bits: Vec<bool> = (0..20).map(|i| (word >> i) & 1 != 0).collect();
+            let expected = ManchesterEncoder::encode_bits(&bits);
+
+            let start = stream.len();
+            ManchesterEncoder::encode_word_into(word, &mut stream);
+            assert_eq!(&stream[start..], &expected[..]);
+            assert_eq!(ManchesterEncoder::encode_word(word), expected);
+
+            assert_eq!(ManchesterDecoder::decode_word(&expected).unwrap(), word);
+        }
+        assert!(ManchesterDecoder::decode_word(&[0x00; 5]).is_err());
+    }
+
     #[test]
     fn test_manchester_encode_decode_roundtrip() {
         let original_bits = vec![true, false, true, false, true, true, false, false];
@@ -175,6 +847,349 @@ mod tests {
         assert_eq!(decoded, original_word);
     }
 
+    /// Oversample Thomas-encoded bits at 4 samples per half-bit
+    fn oversample(bits: &[bool]) -> Vec<u8> {
+        let mut samples = vec![0u8; 8];
+        for &bit in bits {
+            let (first, second) = if bit { (0, 1) } else { (1, 0) };
+            samples.extend([first; 4]);
+            samples.extend([second; 4]);
+        }
+        samples
+    }
+
+    #[test]
+    fn test_edge_jitter_clean() {
+        let bits: Vec<bool> = (0..20).map(|i| i % 3 == 0).collect();
+        let stats = ManchesterDecoder::edge_jitter(&oversample(&bits), 8).unwrap();
+
+        assert_eq!(stats.len(), 1);
+        assert!(stats[0].edges > 20);
+        assert_eq!(stats[0].max, 0.0);
+        assert_eq!(stats[0].mean, 0.0);
+    }
+
+    #[test]
+    fn test_edge_jitter_shifted_edges() {
+        let bits: Vec<bool> = (0..20).map(|i| i % 3 == 0).collect();
+        let mut samples = oversample(&bits);
+
+        // Delay two mid-stream edges by one sample each
+        let edges: Vec<usize> = (1..samples.len())
+            .filter(|&i| samples[i] != samples[i - 1])
+            .collect();
+        for &edge in &edges[5..7] {
+            samples[edge] = samples[edge - 1];
+        }
+
+        let stats = ManchesterDecoder::edge_jitter(&samples, 8).unwrap();
+        assert_eq!(stats[0].max, 1.0);
+        assert!(stats[0].mean > 0.0 && stats[0].mean < 0.2);
+    }
+
+    /// Oversample packed Manchester pairs, with idle low before and after
+    fn oversample_encoded(encoded: &[u8], samples_per_half_bit: usize) -> Vec<u8> {
+        let mut samples = vec![0u8; 3 * samples_per_half_bit];
+        for &byte in encoded {
+            for shift in (0..8).step_by(2) {
+                let pair = (byte >> shift) & 0x3;
+                samples.extend(vec![(pair >> 1) & 1; samples_per_half_bit]);
+                samples.extend(vec![pair & 1; samples_per_half_bit]);
+            }
+        }
+        samples.extend(vec![0u8; 5 * samples_per_half_bit]);
+        samples
+    }
+
+    #[test]
+    fn test_decode_from_samples() {
+        // Bit 0 is a one, so the first transition is a mid-bit edge
+        let encoded = ManchesterEncoder::encode_word(0x12345);
+        let samples = oversample_encoded(&encoded, 4);
+
+        let recovered = ManchesterDecoder::decode_from_samples(&samples, 8).unwrap();
+        assert_eq!(recovered, encoded);
+        assert_eq!(ManchesterDecoder::decode_word(&recovered).unwrap(), 0x12345);
+    }
+
+    #[test]
+    fn test_decode_from_samples_with_jitter() {
+        let encoded = ManchesterEncoder::encode_word_with_sync(0xBEEF, SyncType::CommandStatus);
+        let mut samples = oversample_encoded(&encoded, 8);
+
+        // Move every third edge one sample early or late
+        let edges: Vec<usize> = (1..samples.len())
+            .filter(|&i| samples[i] != samples[i - 1])
+            .collect();
+        for (n, &edge) in edges.iter().enumerate().filter(|(n, _)| n % 3 == 1) {
+            if n % 2 == 0 {
+                samples[edge] = samples[edge - 1];
+            } else {
+                samples[edge - 1] = samples[edge];
+            }
+        }
+
+        let recovered = ManchesterDecoder::decode_from_samples(&samples, 16).unwrap();
+        assert_eq!(
+            ManchesterDecoder::decode_word_with_sync(&recovered).unwrap(),
+            (0xBEEF, SyncType::CommandStatus)
+        );
+    }
+
+    #[test]
+    fn test_decode_words_from_samples_confidence() {
+        let command = ManchesterEncoder::encode_word_with_sync(0xBEEF, SyncType::CommandStatus);
+        let data = ManchesterEncoder::encode_word_with_sync(0x1234, SyncType::Data);
+        let clean = oversample_encoded(&[command, data].concat(), 8);
+
+        let words = ManchesterDecoder::decode_words_from_samples(&clean, 16).unwrap();
+        let decoded: Vec<(u16, SyncType)> = words
+            .iter()
+            .map(|word| (word.data_bits, word.sync))
+            .collect();
+        assert_eq!(
+            decoded,
+            vec![(0xBEEF, SyncType::CommandStatus), (0x1234, SyncType::Data)]
+        );
+        assert!(words.iter().all(|word| word.confidence == 1.0));
+
+        // Move a few edges of the second word three samples late
+        let mut noisy = clean.clone();
+        let edges: Vec<usize> = (1..noisy.len())
+            .filter(|&i| noisy[i] != noisy[i - 1])
+            .collect();
+        let second_word = edges[0] + 20 * 16;
+        for &edge in edges.iter().filter(|&&edge| edge > second_word).step_by(4) {
+            let level = noisy[edge - 1];
+            noisy[edge..edge + 3].fill(level);
+        }
+
+        let words = ManchesterDecoder::decode_words_from_samples(&noisy, 16).unwrap();
+        assert_eq!(words[1].data_bits, 0x1234);
+        assert_eq!(words[0].confidence, 1.0);
+        assert!(words[1].confidence < 0.5);
+
+        // A waveform cut off partway through the second word is a miscount
+        let truncated = &clean[..clean.len() - 12 * 16];
+        assert!(matches!(
+            ManchesterDecoder::decode_words_from_samples(truncated, 16),
+            Err(ParseError::InvalidManchesterEncoding(_))
+        ));
+    }
+
+    #[test]
+    fn test_decode_from_samples_idle() {
+        assert!(ManchesterDecoder::decode_from_samples(&[0; 64], 8)
+            .unwrap()
+            .is_empty());
+        assert!(ManchesterDecoder::decode_from_samples(&[0, 0, 1], 8)
+            .unwrap()
+            .is_empty());
+        assert!(ManchesterDecoder::decode_from_samples(&[0, 1, 0], 3).is_err());
+    }
+
+    #[test]
+    fn test_edge_jitter_rejects_odd_rate() {
+        assert!(ManchesterDecoder::edge_jitter(&[0, 1, 0], 3).is_err());
+    }
+
+    #[test]
+    fn test_encode_words_with_gap() {
+        let first = ManchesterEncoder::encode_word(0x12345);
+        let second = ManchesterEncoder::encode_word(0x2468A);
+
+        // 8 idle half-bits = 4 zero pairs = one zero byte between the words
+        let encoded = ManchesterEncoder::encode_words_with_gap(&[0x12345, 0x2468A], 8).unwrap();
+        assert_eq!(encoded.len(), 11);
+        assert_eq!(&encoded[..5], &first[..]);
+        assert_eq!(encoded[5], 0x00);
+        assert_eq!(&encoded[6..], &second[..]);
+
+        let no_gap = ManchesterEncoder::encode_words_with_gap(&[0x12345, 0x2468A], 0).unwrap();
+        assert_eq!(no_gap, [first, second].concat());
+
+        assert!(ManchesterEncoder::encode_words_with_gap(&[0x12345], 3).is_err());
+    }
+
+    #[test]
+    fn test_remove_gaps() {
+        let words = [0x12345, 0x2468A, 0x0F0F0];
+        let gapless = ManchesterEncoder::encode_words_with_gap(&words, 0).unwrap();
+
+        // 6 idle half-bits leave the later words off byte boundaries
+        let gapped = ManchesterEncoder::encode_words_with_gap(&words, 6).unwrap();
+        assert_eq!(ManchesterDecoder::remove_gaps(&gapped, 6).unwrap(), gapless);
+        let unchanged = ManchesterDecoder::remove_gaps(&gapless, 0).unwrap();
+        assert_eq!(unchanged, gapless);
+
+        assert!(ManchesterDecoder::remove_gaps(&gapped, 5).is_err());
+        // A word where the gap should be is not idle
+        assert!(ManchesterDecoder::remove_gaps(&gapless, 8).is_err());
+
+        // A final word cut short is a miscount, not padding
+        let truncated = &gapped[..gapped.len() - 2];
+        assert!(matches!(
+            ManchesterDecoder::remove_gaps(truncated, 6),
+            Err(ParseError::InvalidWord(_))
+        ));
+    }
+
+    #[test]
+    fn test_decode_word_strict_sync() {
+        let field = 0x1234u32 << 1;
+        for sync in [Word::SYNC_COMMAND_STATUS, Word::SYNC_DATA] {
+            let word = ((sync as u32) << 18) | field;
+            let encoded = ManchesterEncoder::encode_word(word);
+            assert_eq!(
+                ManchesterDecoder::decode_word_checked(&encoded, true).unwrap(),
+                word
+            );
+        }
+
+        for sync in [0b00u32, 0b11] {
+            let word = (sync << 18) | field;
+            let encoded = ManchesterEncoder::encode_word(word);
+            assert!(ManchesterDecoder::decode_word_checked(&encoded, true).is_err());
+            assert_eq!(
+                ManchesterDecoder::decode_word_checked(&encoded, false).unwrap(),
+                word
+            );
+        }
+    }
+
+    #[test]
+    fn test_word_with_sync_round_trip() {
+        for sync in [SyncType::CommandStatus, SyncType::Data] {
+            for data_bits in [0x0000, 0x1234, 0x8001, 0xFFFF] {
+                let encoded = ManchesterEncoder::encode_word_with_sync(data_bits, sync);
+                assert_eq!(encoded.len(), 5);
+                assert_eq!(
+                    ManchesterDecoder::decode_word_with_sync(&encoded).unwrap(),
+                    (data_bits, sync)
+                );
+
+                let ieee = ManchesterType::Ieee;
+                let encoded = ManchesterEncoder::encode_word_with_sync_as(data_bits, sync, ieee);
+                let decoded = ManchesterDecoder::decode_word_with_sync_as(&encoded, ieee);
+                assert_eq!(decoded.unwrap(), (data_bits, sync));
+            }
+        }
+
+        // The polarities differ only in the data and parity bits
+        let sync = SyncType::Data;
+        let thomas = ManchesterEncoder::encode_word_with_sync(0x1234, sync);
+        let ieee = ManchesterEncoder::encode_word_with_sync_as(0x1234, sync, ManchesterType::Ieee);
+        assert_ne!(thomas, ieee);
+        assert_eq!(thomas[0] & 0x3F, ieee[0] & 0x3F);
+
+        // Reading with the wrong polarity inverts every bit, parity included
+        assert!(matches!(
+            ManchesterDecoder::decode_word_with_sync_as(&thomas, ManchesterType::Ieee),
+            Err(ParseError::ParityError(_))
+        ));
+    }
+
+    #[test]
+    fn test_word_with_sync_waveform() {
+        // Command/status sync is pairs 0b11, 0b10, 0b00; data sync is the
+        // mirror image. Both are rejected as ordinary Manchester bits.
+        let command = ManchesterEncoder::encode_word_with_sync(0, SyncType::CommandStatus);
+        assert_eq!(command[0] & 0x3F, 0b00_10_11);
+        let data = ManchesterEncoder::encode_word_with_sync(0, SyncType::Data);
+        assert_eq!(data[0] & 0x3F, 0b11_01_00);
+        assert!(ManchesterDecoder::decode_word(&command).is_err());
+
+        // First data bit (MSB) follows the sync
+        let msb = ManchesterEncoder::encode_word_with_sync(0x8000, SyncType::Data);
+        assert_eq!(msb[0] >> 6, ManchesterEncoder::encode_bit(true));
+    }
+
+    #[test]
+    fn test_find_sync_after_garbage() {
+        let word = ManchesterEncoder::encode_word_with_sync(0x1234, SyncType::CommandStatus);
+        let stream = [vec![0xFF, 0x00, 0x5A], word.clone()].concat();
+
+        assert_eq!(ManchesterDecoder::find_sync(&stream, 0), Some(24));
+        assert_eq!(ManchesterDecoder::word_at(&stream, 24).unwrap(), word);
+        assert_eq!(ManchesterDecoder::find_sync(&stream, 25), None);
+    }
+
+    #[test]
+    fn test_find_sync_half_bit_offset() {
+        let word = ManchesterEncoder::encode_word_with_sync(0xA5A5, SyncType::Data);
+
+        // Delay the word by three idle half-bits
+        let half_bits: Vec<u8> = [0u8; 3]
+            .into_iter()
+            .chain(word.iter().flat_map(|&byte| {
+                (0..8).step_by(2).flat_map(move |shift| {
+                    let pair = (byte >> shift) & 0x3;
+                    [pair >> 1, pair & 1]
+                })
+            }))
+            .collect();
+        let stream: Vec<u8> = half_bits
+            .chunks(8)
+            .map(|chunk| {
+                chunk.chunks(2).enumerate().fold(0u8, |byte, (i, pair)| {
+                    let second = pair.get(1).copied().unwrap_or(0);
+                    byte | (((pair[0] << 1) | second) << (2 * i))
+                })
+            })
+            .collect();
+
+        let offset = ManchesterDecoder::find_sync(&stream, 0).unwrap();
+        assert_eq!(offset, 3);
+        let window = ManchesterDecoder::word_at(&stream, offset).unwrap();
+        assert_eq!(
+            ManchesterDecoder::decode_word_with_sync(&window).unwrap(),
+            (0xA5A5, SyncType::Data)
+        );
+    }
+
+    #[test]
+    fn test_decode_word_with_sync_errors() {
+        let mut bad_parity = ManchesterEncoder::encode_word_with_sync(0x1234, SyncType::Data);
+        bad_parity[4] ^= 0b11 << 6;
+        assert!(ManchesterDecoder::decode_word_with_sync(&bad_parity).is_err());
+
+        let legacy = ManchesterEncoder::encode_word(0x12345);
+        assert!(ManchesterDecoder::decode_word_with_sync(&legacy).is_err());
+
+        assert!(ManchesterDecoder::decode_word_with_sync(&[0; 4]).is_err());
+    }
+
+    #[test]
+    fn test_corrupt_stream_is_deterministic() {
+        let original = ManchesterEncoder::encode_word(0x12345).repeat(20);
+
+        let mut first = original.clone();
+        let mut second = original.clone();
+        let flips = corrupt_stream(&mut first, 0.05, 42);
+        corrupt_stream(&mut second, 0.05, 42);
+
+        assert!(flips > 0);
+        assert_eq!(first, second);
+        assert_ne!(first, original);
+
+        let mut other_seed = original.clone();
+        corrupt_stream(&mut other_seed, 0.05, 7);
+        assert_ne!(other_seed, first);
+    }
+
+    #[test]
+    fn test_corrupt_stream_rate_extremes() {
+        let original = ManchesterEncoder::encode_word(0x12345);
+
+        let mut untouched = original.clone();
+        assert_eq!(corrupt_stream(&mut untouched, 0.0, 1), 0);
+        assert_eq!(untouched, original);
+
+        let mut inverted = original.clone();
+        assert_eq!(corrupt_stream(&mut inverted, 1.0, 1), original.len() * 8);
+        assert!(inverted.iter().zip(&original).all(|(a, b)| *a == !*b));
+    }
+
     #[test]
     fn test_manchester_invalid_pattern() {
         let invalid_data = vec![0b00, 0b11];
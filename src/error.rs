@@ -1,62 +1,82 @@
 //! Error types for MIL-STD-1553B parsing
 
-use thiserror::Error;
+use alloc::string::String;
+use core::fmt;
 
 /// Result type for MIL-STD-1553B operations
-pub type Result<T> = std::result::Result<T, ParseError>;
+pub type Result<T> = core::result::Result<T, ParseError>;
 
 /// Error types encountered during MIL-STD-1553B parsing and validation
-#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ParseError {
     /// Invalid word format or structure
-    #[error("Invalid word: {0}")]
     InvalidWord(String),
 
     /// Parity check failed
-    #[error("Parity error: {0}")]
     ParityError(String),
 
     /// Invalid address specified
-    #[error("Invalid address: {0}")]
     InvalidAddress(String),
 
     /// Invalid message type
-    #[error("Invalid message type: {0}")]
     InvalidMessageType(String),
 
     /// Insufficient data to parse
-    #[error("Insufficient data: {0}")]
     InsufficientData(String),
 
+    /// Number of data words disagrees with the command's word count
+    WordCountMismatch(String),
+
     /// Invalid Manchester encoding
-    #[error("Invalid Manchester encoding: {0}")]
     InvalidManchesterEncoding(String),
 
+    /// Word framing is invalid (e.g. a non-zero start bit)
+    FramingError(String),
+
     /// Invalid command format
-    #[error("Invalid command: {0}")]
     InvalidCommand(String),
 
     /// Invalid response format
-    #[error("Invalid response: {0}")]
     InvalidResponse(String),
 
     /// Status word error
-    #[error("Status error: {0}")]
     StatusError(String),
 
     /// Bus error detected
-    #[error("Bus error: {0}")]
     BusError(String),
 
     /// Generic parsing error
-    #[error("Parse error: {0}")]
     ParseFailed(String),
 
     /// Validation error
-    #[error("Validation error: {0}")]
     ValidationError(String),
 }
 
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidWord(msg) => write!(f, "Invalid word: {}", msg),
+            ParseError::ParityError(msg) => write!(f, "Parity error: {}", msg),
+            ParseError::InvalidAddress(msg) => write!(f, "Invalid address: {}", msg),
+            ParseError::InvalidMessageType(msg) => write!(f, "Invalid message type: {}", msg),
+            ParseError::InsufficientData(msg) => write!(f, "Insufficient data: {}", msg),
+            ParseError::WordCountMismatch(msg) => write!(f, "Word count mismatch: {}", msg),
+            ParseError::InvalidManchesterEncoding(msg) => {
+                write!(f, "Invalid Manchester encoding: {}", msg)
+            }
+            ParseError::FramingError(msg) => write!(f, "Framing error: {}", msg),
+            ParseError::InvalidCommand(msg) => write!(f, "Invalid command: {}", msg),
+            ParseError::InvalidResponse(msg) => write!(f, "Invalid response: {}", msg),
+            ParseError::StatusError(msg) => write!(f, "Status error: {}", msg),
+            ParseError::BusError(msg) => write!(f, "Bus error: {}", msg),
+            ParseError::ParseFailed(msg) => write!(f, "Parse error: {}", msg),
+            ParseError::ValidationError(msg) => write!(f, "Validation error: {}", msg),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
+
 impl ParseError {
     /// Create a new InvalidWord error
     pub fn invalid_word(msg: impl Into<String>) -> Self {
@@ -83,11 +103,21 @@ impl ParseError {
         ParseError::InsufficientData(msg.into())
     }
 
+    /// Create a new WordCountMismatch error
+    pub fn word_count_mismatch(msg: impl Into<String>) -> Self {
+        ParseError::WordCountMismatch(msg.into())
+    }
+
     /// Create a new InvalidManchesterEncoding error
     pub fn invalid_manchester(msg: impl Into<String>) -> Self {
         ParseError::InvalidManchesterEncoding(msg.into())
     }
 
+    /// Create a new FramingError
+    pub fn framing_error(msg: impl Into<String>) -> Self {
+        ParseError::FramingError(msg.into())
+    }
+
     /// Create a new ParseFailed error
     pub fn parse_failed(msg: impl Into<String>) -> Self {
         ParseError::ParseFailed(msg.into())
@@ -0,0 +1,155 @@
+//! Export of captures and bus statistics to external formats
+
+#[cfg(feature = "serde")]
+use crate::error::{ParseError, Result};
+#[cfg(feature = "serde")]
+use crate::parser::Transaction;
+#[cfg(feature = "prometheus")]
+use crate::protocol::{BusController, RTStats};
+#[cfg(feature = "prometheus")]
+use std::fmt::Write;
+
+/// Render a capture as JSON lines, one compact object per transaction
+///
+/// Every line, including the last, is terminated by a newline. Fails if a
+/// transaction cannot be serialized.
+#[cfg(feature = "serde")]
+pub fn jsonl(transactions: &[Transaction]) -> Result<String> {
+    let mut out = String::new();
+    for (index, transaction) in transactions.iter().enumerate() {
+        let line = serde_json::to_string(transaction).map_err(|err| {
+            ParseError::parse_failed(format!("Transaction {} not serialized: {}", index, err))
+        })?;
+        out.push_str(&line);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+/// Render RT statistics in the Prometheus text exposition format
+///
+/// Emits one sample per registered RT for each metric, labelled with the
+/// RT address and ordered by address.
+#[cfg(feature = "prometheus")]
+pub fn prometheus(bc: &BusController) -> String {
+    let mut stats = bc.get_all_stats();
+    stats.sort_by_key(|rt| rt.address);
+
+    let mut out = String::new();
+    write_metric(
+        &mut out,
+        "milstd1553_rt_errors_total",
+        "counter",
+        "Failed transactions per RT",
+        &stats,
+        |rt| rt.error_count as u64,
+    );
+    write_metric(
+        &mut out,
+        "milstd1553_rt_successes_total",
+        "counter",
+        "Successful transactions per RT",
+        &stats,
+        |rt| rt.success_count as u64,
+    );
+    write_metric(
+        &mut out,
+        "milstd1553_rt_responding",
+        "gauge",
+        "Whether the RT responded within the response timeout",
+        &stats,
+        |rt| rt.is_responding as u64,
+    );
+    out
+}
+
+/// Append one metric family with a sample per RT
+#[cfg(feature = "prometheus")]
+fn write_metric(
+    out: &mut String,
+    name: &str,
+    kind: &str,
+    help: &str,
+    stats: &[RTStats],
+    value: impl Fn(&RTStats) -> u64,
+) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+    for rt in stats {
+        let _ = writeln!(
+            out,
+            "{}{{address=\"{}\"}} {}",
+            name,
+            rt.address.value(),
+            value(rt)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Address, Bus};
+    use crate::error::Result;
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_jsonl_export() -> Result<()> {
+        use crate::message::{Command, CommandType, Message, SubAddress};
+
+        let command = Command::new(
+            Address::new(5)?,
+            CommandType::Transmit,
+            SubAddress::new(1)?,
+            4,
+        )?;
+        let capture: Vec<Transaction> = [Bus::BusA, Bus::BusB, Bus::BusA]
+            .into_iter()
+            .enumerate()
+            .map(|(index, bus)| Transaction {
+                bus,
+                message: Message::CommandOnly(command.clone()),
+                timestamp_us: Some(index as u64 * 100),
+                response_gap_us: None,
+                sequence: Some(index as u32),
+                decoded: None,
+            })
+            .collect();
+
+        let text = jsonl(&capture)?;
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), capture.len());
+        for (line, original) in lines.iter().zip(&capture) {
+            let parsed: Transaction = serde_json::from_str(line).unwrap();
+            assert_eq!(parsed.message, original.message);
+            assert_eq!(parsed.bus, original.bus);
+            assert_eq!(parsed.timestamp_us, original.timestamp_us);
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "prometheus")]
+    #[test]
+    fn test_prometheus_export() -> Result<()> {
+        let mut bc = BusController::new(Bus::BusA);
+        // Long enough that the RTs still count as responding when exported
+        bc.response_timeout = core::time::Duration::from_secs(3600);
+        bc.register_rts(&[5, 2])?;
+        for _ in 0..3 {
+            bc.record_rt_error(Address::new(5)?)?;
+        }
+        bc.record_rt_success(Address::new(2)?)?;
+
+        let text = prometheus(&bc);
+        assert!(text.contains("# TYPE milstd1553_rt_errors_total counter\n"));
+        assert!(text.contains("milstd1553_rt_errors_total{address=\"5\"} 3\n"));
+        assert!(text.contains("milstd1553_rt_errors_total{address=\"2\"} 0\n"));
+        assert!(text.contains("milstd1553_rt_successes_total{address=\"2\"} 1\n"));
+        assert!(text.contains("milstd1553_rt_responding{address=\"5\"} 1\n"));
+        assert!(
+            text.find("errors_total{address=\"2\"}").unwrap()
+                < text.find("errors_total{address=\"5\"}").unwrap()
+        );
+        Ok(())
+    }
+}
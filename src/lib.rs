@@ -12,7 +12,10 @@
 //!
 //! ## Features
 //!
+//! - `std` (default): Enable the standard library, `analysis`, and RT
+//!   timing in `protocol`. Without it the crate is `no_std` and needs `alloc`.
 //! - `serde`: Enable serialization/deserialization support
+//! - `prometheus`: Enable Prometheus text export of RT statistics
 //!
 //! ## Example
 //!
@@ -25,26 +28,39 @@
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+pub mod analysis;
 pub mod core;
 pub mod encoding;
 pub mod error;
+#[cfg(any(feature = "prometheus", feature = "serde"))]
+pub mod export;
 pub mod message;
 pub mod parser;
 pub mod protocol;
 
-pub use core::{Address, Word, WordType};
+pub use core::{Address, SyncType, Word, WordType};
 pub use error::{ParseError, Result};
 pub use message::{Command, Message};
 pub use parser::Parser;
 
 /// The MIL-STD-1553B specification constants
 pub mod spec {
+    use alloc::format;
+
     /// Clock frequency in Hz
     pub const CLOCK_FREQUENCY: u32 = 1_000_000; // 1 MHz
 
     /// Word length in bits
     pub const WORD_LENGTH: usize = 20;
 
+    /// Length of the sync pulse that opens each word, in bit times
+    pub const SYNC_LENGTH: usize = 3;
+
     /// Maximum number of Remote Terminals
     pub const MAX_REMOTE_TERMINALS: u8 = 30;
 
@@ -53,4 +69,56 @@ pub mod spec {
 
     /// Maximum data word rate in bits per second
     pub const MAX_DATA_WORD_RATE: u32 = 1_000_000; // 1 Mbps
+
+    /// Duration of one word on the bus in microseconds
+    pub const WORD_TIME_US: u32 = 20;
+
+    /// Minimum RT response time in microseconds
+    pub const MIN_RESPONSE_TIME_US: u32 = 4;
+
+    /// Maximum RT response time in microseconds
+    pub const MAX_RESPONSE_TIME_US: u32 = 12;
+
+    /// Minimum gap between messages in microseconds
+    pub const MIN_INTERMESSAGE_GAP_US: u32 = 4;
+
+    /// Check that a decoded word has exactly [`WORD_LENGTH`] bits
+    pub fn validate_word_length(bits: usize) -> crate::Result<()> {
+        if bits != WORD_LENGTH {
+            return Err(crate::ParseError::invalid_word(format!(
+                "Decoded {} bits, expected {}",
+                bits, WORD_LENGTH
+            )));
+        }
+        Ok(())
+    }
+
+    /// Check that an encoded word has exactly [`MANCHESTER_BITS_PER_WORD`] half-bits
+    pub fn validate_manchester_length(half_bits: usize) -> crate::Result<()> {
+        if half_bits != MANCHESTER_BITS_PER_WORD {
+            return Err(crate::ParseError::invalid_manchester(format!(
+                "Encoded word has {} half-bits, expected {}",
+                half_bits, MANCHESTER_BITS_PER_WORD
+            )));
+        }
+        Ok(())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_validate_word_length() {
+            assert!(validate_word_length(WORD_LENGTH).is_ok());
+            assert!(validate_word_length(19).is_err());
+            assert!(validate_word_length(21).is_err());
+        }
+
+        #[test]
+        fn test_validate_manchester_length() {
+            assert!(validate_manchester_length(40).is_ok());
+            assert!(validate_manchester_length(38).is_err());
+        }
+    }
 }
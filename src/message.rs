@@ -2,9 +2,12 @@
 
 use crate::core::{Address, Word, WordType};
 use crate::error::{ParseError, Result};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 /// Sub-address for Read/Write operations
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubAddress(u8);
 
@@ -37,43 +40,70 @@ pub enum CommandType {
 }
 
 /// Mode code command (special commands sent to specific addresses)
+///
+/// This enum is `#[non_exhaustive]`: further mode codes may be added, so
+/// matches outside this crate must include a wildcard arm.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum ModeCode {
-    /// Synchronize (broadcast mode code)
-    Synchronize = 0,
+    /// Dynamic Bus Control
+    DynamicBusControl = 0,
+    /// Synchronize (without data word)
+    Synchronize = 1,
     /// Transmit Status Word
-    TransmitStatusWord = 1,
+    TransmitStatusWord = 2,
     /// Initiate Self Test
-    InitiateSelfTest = 2,
-    /// Transmit Last Command Word
-    TransmitLastCommandWord = 3,
-    /// Transmit Built-In Test Result
-    TransmitBuiltInTestResult = 4,
-    /// Synchronize (alternate)
-    SynchronizeAlt = 5,
-    /// Transmit Vector Word
-    TransmitVectorWord = 6,
-    /// Synchronize (alternate 2)
-    SynchronizeAlt2 = 7,
-    /// Transmit Last Data Word
-    TransmitLastDataWord = 8,
+    InitiateSelfTest = 3,
+    /// Transmitter Shutdown
+    TransmitterShutdown = 4,
+    /// Override Transmitter Shutdown
+    OverrideTransmitterShutdown = 5,
+    /// Inhibit Terminal Flag Bit
+    InhibitTerminalFlag = 6,
+    /// Override Inhibit Terminal Flag Bit
+    OverrideInhibitTerminalFlag = 7,
+    /// Reset Remote Terminal
+    ResetRemoteTerminal = 8,
+    /// Transmit Vector Word (data word follows)
+    TransmitVectorWord = 16,
+    /// Synchronize with data word
+    SynchronizeWithData = 17,
+    /// Transmit Last Command Word (data word follows)
+    TransmitLastCommandWord = 18,
+    /// Transmit Built-In Test Word (data word follows)
+    TransmitBuiltInTestResult = 19,
+    /// Selected Transmitter Shutdown (data word follows)
+    SelectedTransmitterShutdown = 20,
+    /// Override Selected Transmitter Shutdown (data word follows)
+    OverrideSelectedTransmitterShutdown = 21,
 }
 
 impl TryFrom<u8> for ModeCode {
     type Error = ParseError;
 
+    /// Convert a 5-bit mode code, rejecting the reserved codes 9-15 and 22-31
     fn try_from(value: u8) -> Result<Self> {
         match value {
-            0 => Ok(ModeCode::Synchronize),
-            1 => Ok(ModeCode::TransmitStatusWord),
-            2 => Ok(ModeCode::InitiateSelfTest),
-            3 => Ok(ModeCode::TransmitLastCommandWord),
-            4 => Ok(ModeCode::TransmitBuiltInTestResult),
-            5 => Ok(ModeCode::SynchronizeAlt),
-            6 => Ok(ModeCode::TransmitVectorWord),
-            7 => Ok(ModeCode::SynchronizeAlt2),
-            8 => Ok(ModeCode::TransmitLastDataWord),
+            0 => Ok(ModeCode::DynamicBusControl),
+            1 => Ok(ModeCode::Synchronize),
+            2 => Ok(ModeCode::TransmitStatusWord),
+            3 => Ok(ModeCode::InitiateSelfTest),
+            4 => Ok(ModeCode::TransmitterShutdown),
+            5 => Ok(ModeCode::OverrideTransmitterShutdown),
+            6 => Ok(ModeCode::InhibitTerminalFlag),
+            7 => Ok(ModeCode::OverrideInhibitTerminalFlag),
+            8 => Ok(ModeCode::ResetRemoteTerminal),
+            16 => Ok(ModeCode::TransmitVectorWord),
+            17 => Ok(ModeCode::SynchronizeWithData),
+            18 => Ok(ModeCode::TransmitLastCommandWord),
+            19 => Ok(ModeCode::TransmitBuiltInTestResult),
+            20 => Ok(ModeCode::SelectedTransmitterShutdown),
+            21 => Ok(ModeCode::OverrideSelectedTransmitterShutdown),
+            9..=15 | 22..=31 => Err(ParseError::invalid_message_type(format!(
+                "Reserved mode code: {}",
+                value
+            ))),
             _ => Err(ParseError::invalid_message_type(format!(
                 "Unknown mode code: {}",
                 value
@@ -82,14 +112,23 @@ impl TryFrom<u8> for ModeCode {
     }
 }
 
+impl ModeCode {
+    /// Check whether the mode command is accompanied by a data word
+    ///
+    /// Mode codes 0-15 carry no data word; codes 16-31 carry exactly one.
+    pub fn has_data_word(&self) -> bool {
+        (*self as u8) & 0x10 != 0
+    }
+}
+
 /// A MIL-STD-1553B command word
 ///
 /// Format:
-/// - Bits 19-16: Address (0-31)
-/// - Bit 15: Transmit/Receive flag
-/// - Bits 14-10: Sub-address or Mode Code
-/// - Bits 9-0: Data word count or mode code data
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// - Bits 15-11: Address (0-31)
+/// - Bit 10: Transmit/Receive flag
+/// - Bits 9-5: Sub-address or Mode Code
+/// - Bits 4-0: Data word count or mode code (0 means 32 words)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Command {
     /// Address of the target device
@@ -98,12 +137,16 @@ pub struct Command {
     pub command_type: CommandType,
     /// Sub-address (5 bits)
     pub sub_address: SubAddress,
-    /// Data word count (10 bits, 0-20, 0 means 32 words)
+    /// Data word count (1-32, encoded as 0 for 32 words), or the mode code
+    /// for mode commands (see [`Command::mode_code`])
     pub word_count: u16,
 }
 
 impl Command {
     /// Create a new command
+    ///
+    /// For mode commands (sub-address 0 or 31) `word_count` is the 5-bit
+    /// mode code field, so it may not exceed 31.
     pub fn new(
         address: Address,
         command_type: CommandType,
@@ -117,39 +160,91 @@ impl Command {
             )));
         }
 
-        Ok(Command {
+        let command = Command {
             address,
             command_type,
             sub_address,
             word_count,
-        })
+        };
+        if command.is_mode_command() && word_count > 31 {
+            return Err(ParseError::invalid_command(format!(
+                "Mode code {} exceeds maximum of 31",
+                word_count
+            )));
+        }
+        Ok(command)
+    }
+
+    /// Create a mode command
+    ///
+    /// Mode commands use sub-address 0, with the mode code carried in the
+    /// word count field.
+    pub fn new_mode_code(address: Address, command_type: CommandType, code: ModeCode) -> Self {
+        Command {
+            address,
+            command_type,
+            sub_address: SubAddress(0),
+            word_count: code as u16,
+        }
+    }
+
+    /// Check whether the sub-address marks this as a mode command (0 or 31)
+    pub fn is_mode_command(&self) -> bool {
+        matches!(self.sub_address.value(), 0 | 31)
+    }
+
+    /// Check whether the addressed RT answers this command with a status word
+    ///
+    /// Broadcast commands, mode commands included, are never answered.
+    pub fn expects_status(&self) -> bool {
+        !self.address.is_broadcast()
+    }
+
+    /// Get the mode code of a mode command
+    ///
+    /// Returns `None` for ordinary commands and for mode codes this crate
+    /// does not define.
+    pub fn mode_code(&self) -> Option<ModeCode> {
+        if !self.is_mode_command() {
+            return None;
+        }
+        ModeCode::try_from((self.word_count & 0x1F) as u8).ok()
+    }
+
+    /// Number of data words that accompany this command
+    ///
+    /// A word count of 0 means 32. Mode commands carry one data word for
+    /// mode codes 16-31 and none otherwise.
+    pub fn expected_data_words(&self) -> usize {
+        if self.is_mode_command() {
+            return usize::from(self.word_count & 0x10 != 0);
+        }
+        match self.word_count {
+            0 => 32,
+            count => count as usize,
+        }
     }
 
     /// Encode command as a word
     pub fn to_word(&self) -> Result<Word> {
         let mut word = 0u32;
 
-        // Address (bits 15-12, occupying the high nibble of data)
-        word |= (self.address.value() as u32 & 0x0F) << 12;
+        // Address (bits 15-11)
+        word |= (self.address.value() as u32 & 0x1F) << 11;
 
-        // Transmit/Receive bit (bit 11)
+        // Transmit/Receive bit (bit 10)
         word |= match self.command_type {
-            CommandType::Transmit => 0x0800,
+            CommandType::Transmit => 0x0400,
             CommandType::Receive => 0x0000,
         };
 
-        // Sub-address (bits 10-6)
-        word |= (self.sub_address.value() as u32 & 0x1F) << 6;
+        // Sub-address (bits 9-5)
+        word |= (self.sub_address.value() as u32 & 0x1F) << 5;
 
-        // Word count (bits 5-0)
-        word |= (self.word_count & 0x3F) as u32;
+        // Word count (bits 4-0, 32 wraps to 0)
+        word |= (self.word_count & 0x1F) as u32;
 
-        // Shift to data position (bits 16-1) and add parity
-        let data_in_position = word << 1; // Now in bits 16-1
-        let parity = Word::calculate_parity(word as u16) as u32;
-        let final_word = data_in_position | (parity << 17);
-
-        Ok(Word::new_unchecked(final_word, WordType::Command))
+        Ok(Word::from_data_bits(word as u16, WordType::Command))
     }
 
     /// Decode command from a word
@@ -161,30 +256,55 @@ impl Command {
         }
 
         let data = word.data() >> 1; // Remove start bit
-        let address = Address::new(((data >> 12) & 0x0F) as u8)?;
-        let command_type = if (data & 0x0800) != 0 {
+        let address = Address::new(((data >> 11) & 0x1F) as u8)?;
+        let command_type = if (data & 0x0400) != 0 {
             CommandType::Transmit
         } else {
             CommandType::Receive
         };
-        let sub_address = SubAddress::new(((data >> 6) & 0x1F) as u8)?;
-        let word_count = (data & 0x3F) as u16;
+        let sub_address = SubAddress::new(((data >> 5) & 0x1F) as u8)?;
+        let field = (data & 0x1F) as u16;
 
-        Ok(Command {
+        let mut command = Command {
             address,
             command_type,
             sub_address,
-            word_count: if word_count == 0 { 32 } else { word_count },
-        })
+            word_count: field,
+        };
+        // Outside mode commands the field is a word count, where 0 means 32
+        if !command.is_mode_command() && field == 0 {
+            command.word_count = 32;
+        }
+        Ok(command)
+    }
+}
+
+impl core::fmt::Display for Command {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let direction = match self.command_type {
+            CommandType::Transmit => "TX",
+            CommandType::Receive => "RX",
+        };
+        write!(
+            f,
+            "CMD {} {} SA-{}",
+            self.address,
+            direction,
+            self.sub_address.value()
+        )?;
+        if self.is_mode_command() {
+            write!(f, " MC-{}", self.word_count & 0x1F)
+        } else {
+            write!(f, " WC-{}", self.word_count)
+        }
     }
 }
 
 /// A MIL-STD-1553B status word
 ///
-/// Format (from Remote Terminal):
-/// - Bits 19-16: Address
-/// - Bits 15-11: Status flags
-/// - Bits 10-0: Message error code (11 bits)
+/// Format of the 16-bit data field (from Remote Terminal):
+/// - Bits 15-11: Address (0-31)
+/// - Bits 10-0: Status field (11 bits), carrying the status flags
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StatusWord {
@@ -192,28 +312,41 @@ pub struct StatusWord {
     pub address: Address,
     /// Status flags
     pub flags: StatusFlags,
-    /// Message error code
+    /// Message error code: the full 11-bit status field, including the
+    /// bits reported in `flags`
     pub error_code: u16,
 }
 
 /// Status flags in a MIL-STD-1553B status word
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Each flag occupies one bit of the 11-bit status field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StatusFlags {
-    /// Reserved flag
+    /// Reserved flag (bit 7)
     pub reserved: bool,
-    /// Subsystem flag
+    /// Subsystem flag (bit 2)
     pub subsystem_flag: bool,
-    /// Busy flag
+    /// Busy flag (bit 3)
     pub busy: bool,
-    /// BCast (broadcast) flag
+    /// BCast (broadcast) flag (bit 4)
     pub broadcast: bool,
-    /// Parity error flag
+    /// Parity error flag, reported as Message Error (bit 10)
     pub parity_error: bool,
+    /// Instrumentation flag (bit 9)
+    pub instrumentation: bool,
+    /// Service Request flag (bit 8)
+    pub service_request: bool,
+    /// Dynamic Bus Control Acceptance flag (bit 1)
+    pub dynamic_bus_control: bool,
+    /// Terminal flag (bit 0)
+    pub terminal_flag: bool,
 }
 
 impl StatusFlags {
     /// Create a new status flags struct
+    ///
+    /// The flags not taken as arguments start cleared.
     pub fn new(reserved: bool, subsystem: bool, busy: bool, broadcast: bool, parity: bool) -> Self {
         StatusFlags {
             reserved,
@@ -221,44 +354,77 @@ impl StatusFlags {
             busy,
             broadcast,
             parity_error: parity,
+            ..Default::default()
         }
     }
 
-    /// Encode flags as bits
-    fn encode(&self) -> u8 {
-        let mut flags = 0u8;
+    /// Check the Message Error bit, stored as `parity_error`
+    pub fn message_error(&self) -> bool {
+        self.parity_error
+    }
+
+    /// Bits of the 11-bit status field that carry a flag
+    const BITS: u16 = 0x79F;
+
+    /// Encode flags as bits of the 11-bit status field
+    fn encode(&self) -> u16 {
+        let mut flags = 0u16;
+        if self.parity_error {
+            flags |= 0x400;
+        }
+        if self.instrumentation {
+            flags |= 0x200;
+        }
+        if self.service_request {
+            flags |= 0x100;
+        }
         if self.reserved {
-            flags |= 0x10;
+            flags |= 0x080;
         }
-        if self.subsystem_flag {
-            flags |= 0x08;
+        if self.broadcast {
+            flags |= 0x010;
         }
         if self.busy {
-            flags |= 0x04;
+            flags |= 0x008;
         }
-        if self.broadcast {
-            flags |= 0x02;
+        if self.subsystem_flag {
+            flags |= 0x004;
         }
-        if self.parity_error {
-            flags |= 0x01;
+        if self.dynamic_bus_control {
+            flags |= 0x002;
+        }
+        if self.terminal_flag {
+            flags |= 0x001;
         }
         flags
     }
 
-    /// Decode flags from bits
-    fn decode(bits: u8) -> Self {
+    /// Decode flags from bits of the 11-bit status field
+    fn decode(bits: u16) -> Self {
         StatusFlags {
-            reserved: (bits & 0x10) != 0,
-            subsystem_flag: (bits & 0x08) != 0,
-            busy: (bits & 0x04) != 0,
-            broadcast: (bits & 0x02) != 0,
-            parity_error: (bits & 0x01) != 0,
+            reserved: (bits & 0x080) != 0,
+            subsystem_flag: (bits & 0x004) != 0,
+            busy: (bits & 0x008) != 0,
+            broadcast: (bits & 0x010) != 0,
+            parity_error: (bits & 0x400) != 0,
+            instrumentation: (bits & 0x200) != 0,
+            service_request: (bits & 0x100) != 0,
+            dynamic_bus_control: (bits & 0x002) != 0,
+            terminal_flag: (bits & 0x001) != 0,
         }
     }
 }
 
 impl StatusWord {
     /// Create a new status word
+    ///
+    /// `error_code` is the whole 11-bit status field, flag bits included.
+    /// The flag bits may come from either argument: set in `flags` with
+    /// `error_code` carrying none, or set in `error_code` with `flags`
+    /// empty. When both carry flag bits they must agree, otherwise the
+    /// inputs conflict and an error is returned. The resulting `flags`
+    /// and `error_code` describe the same field, so the status
+    /// round-trips through [`Self::to_word`] and [`Self::from_word`].
     pub fn new(address: Address, flags: StatusFlags, error_code: u16) -> Result<Self> {
         if error_code > 0x7FF {
             return Err(ParseError::invalid_response(format!(
@@ -267,10 +433,20 @@ impl StatusWord {
             )));
         }
 
+        let given = error_code & StatusFlags::BITS;
+        if flags.encode() != 0 && given != 0 && given != flags.encode() {
+            return Err(ParseError::invalid_response(format!(
+                "Error code flag bits {:#05x} conflict with flags {:#05x}",
+                given,
+                flags.encode()
+            )));
+        }
+
+        let status = error_code | flags.encode();
         Ok(StatusWord {
             address,
-            flags,
-            error_code,
+            flags: StatusFlags::decode(status),
+            error_code: status,
         })
     }
 
@@ -278,21 +454,13 @@ impl StatusWord {
     pub fn to_word(&self) -> Result<Word> {
         let mut word = 0u32;
 
-        // Address (bits 15-12)
-        word |= (self.address.value() as u32 & 0x0F) << 12;
-
-        // Status flags (bits 11-7)
-        word |= (self.flags.encode() as u32 & 0x1F) << 7;
-
-        // Error code (bits 6-0)
-        word |= (self.error_code & 0x7F) as u32;
+        // Address (bits 15-11)
+        word |= (self.address.value() as u32 & 0x1F) << 11;
 
-        // Shift to data position (bits 16-1) and add parity
-        let data_in_position = word << 1; // Now in bits 16-1
-        let parity = Word::calculate_parity(word as u16) as u32;
-        let final_word = data_in_position | (parity << 17);
+        // Status field with flags (bits 10-0)
+        word |= ((self.error_code | self.flags.encode()) & 0x7FF) as u32;
 
-        Ok(Word::new_unchecked(final_word, WordType::Status))
+        Ok(Word::from_data_bits(word as u16, WordType::Status))
     }
 
     /// Decode status word from a word
@@ -304,9 +472,9 @@ impl StatusWord {
         }
 
         let data = word.data() >> 1; // Remove start bit
-        let address = Address::new(((data >> 12) & 0x0F) as u8)?;
-        let flags = StatusFlags::decode(((data >> 7) & 0x1F) as u8);
-        let error_code = (data & 0x7F) as u16;
+        let address = Address::new(((data >> 11) & 0x1F) as u8)?;
+        let error_code = (data & 0x7FF) as u16;
+        let flags = StatusFlags::decode(error_code);
 
         Ok(StatusWord {
             address,
@@ -314,16 +482,157 @@ impl StatusWord {
             error_code,
         })
     }
+
+    /// List the conditions reported by the status field, most severe first
+    ///
+    /// Returns an empty list for a clean status.
+    pub fn conditions(&self) -> Vec<StatusCondition> {
+        [
+            (0x400, StatusCondition::MessageError),
+            (0x200, StatusCondition::Instrumentation),
+            (0x100, StatusCondition::ServiceRequest),
+            (0x0E0, StatusCondition::ReservedBitsSet),
+            (0x010, StatusCondition::BroadcastReceived),
+            (0x008, StatusCondition::Busy),
+            (0x004, StatusCondition::SubsystemFlag),
+            (0x002, StatusCondition::DynamicBusControlAccepted),
+            (0x001, StatusCondition::TerminalFlag),
+        ]
+        .into_iter()
+        .filter(|(mask, _)| self.error_code & mask != 0)
+        .map(|(_, condition)| condition)
+        .collect()
+    }
+
+    /// Describe the status field in words
+    ///
+    /// Conditions are joined with `"; "`, or `"No errors"` for a clean
+    /// status.
+    pub fn describe_error(&self) -> String {
+        let conditions = self.conditions();
+        if conditions.is_empty() {
+            return "No errors".to_string();
+        }
+        conditions
+            .iter()
+            .map(|condition| condition.description())
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+}
+
+/// Condition reported by a bit of the status field
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StatusCondition {
+    /// The RT rejected the last message (bit 10)
+    MessageError,
+    /// Instrumentation bit set (bit 9)
+    Instrumentation,
+    /// The RT requests service from the BC (bit 8)
+    ServiceRequest,
+    /// One of the reserved bits (7-5) is set
+    ReservedBitsSet,
+    /// The last command was a valid broadcast (bit 4)
+    BroadcastReceived,
+    /// The RT cannot move data right now (bit 3)
+    Busy,
+    /// A subsystem fault was detected (bit 2)
+    SubsystemFlag,
+    /// The RT accepted bus control (bit 1)
+    DynamicBusControlAccepted,
+    /// The RT detected an internal fault (bit 0)
+    TerminalFlag,
+}
+
+impl StatusCondition {
+    /// Short human-readable description of the condition
+    pub fn description(&self) -> &'static str {
+        match self {
+            StatusCondition::MessageError => "Message error",
+            StatusCondition::Instrumentation => "Instrumentation",
+            StatusCondition::ServiceRequest => "Service request",
+            StatusCondition::ReservedBitsSet => "Reserved bits set",
+            StatusCondition::BroadcastReceived => "Broadcast command received",
+            StatusCondition::Busy => "Terminal busy",
+            StatusCondition::SubsystemFlag => "Subsystem fault",
+            StatusCondition::DynamicBusControlAccepted => "Dynamic bus control accepted",
+            StatusCondition::TerminalFlag => "Terminal fault",
+        }
+    }
+}
+
+impl core::fmt::Display for StatusWord {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "STATUS {}", self.address)?;
+
+        let flags = [
+            (self.flags.parity_error, "PARITY"),
+            (self.flags.instrumentation, "INSTRUMENTATION"),
+            (self.flags.service_request, "SERVICE_REQUEST"),
+            (self.flags.reserved, "RESERVED"),
+            (self.flags.broadcast, "BROADCAST"),
+            (self.flags.busy, "BUSY"),
+            (self.flags.subsystem_flag, "SUBSYSTEM"),
+            (self.flags.dynamic_bus_control, "DYNAMIC_BUS_CONTROL"),
+            (self.flags.terminal_flag, "TERMINAL"),
+        ];
+        let set: Vec<&str> = flags
+            .iter()
+            .filter(|(set, _)| *set)
+            .map(|(_, name)| *name)
+            .collect();
+        if !set.is_empty() {
+            write!(f, " [{}]", set.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Originator of the data words in a message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DataSource {
+    /// Sent by the Bus Controller (receive command)
+    Bc,
+    /// Sent by the Remote Terminal (transmit command)
+    Rt,
 }
 
 /// A complete message in MIL-STD-1553B protocol
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Message {
-    /// Command followed by optional data words
-    CommandData {
+    /// Receive command followed by the data words the BC sends to the RT,
+    /// then the RT's status word if it answered
+    BcToRt {
+        command: Command,
+        data_words: Vec<Word>,
+        status: Option<StatusWord>,
+    },
+    /// Transmit command answered by the RT's status word and data words
+    RtToBc {
         command: Command,
+        status: StatusWord,
+        data_words: Vec<Word>,
+    },
+    /// Transfer between two RTs: a receive command to one RT and a
+    /// transmit command to another, the transmitting RT's status and data,
+    /// then the receiving RT's status
+    ///
+    /// The receiving status is `None` when the receive command is
+    /// broadcast, since broadcast receivers do not answer.
+    RtToRt {
+        receive_command: Command,
+        transmit_command: Command,
         data_words: Vec<Word>,
+        receiving_status: Option<StatusWord>,
+        transmitting_status: StatusWord,
+    },
+    /// Command answered by a status word, with no data words
+    CommandStatus {
+        command: Command,
+        status: StatusWord,
     },
     /// Status word response
     Status(StatusWord),
@@ -333,21 +642,203 @@ pub enum Message {
 
 impl Message {
     /// Get the address associated with this message
+    ///
+    /// For RT-to-RT transfers this is the receiving RT.
     pub fn address(&self) -> Address {
         match self {
-            Message::CommandData { command, .. } => command.address,
+            Message::BcToRt { command, .. } => command.address,
+            Message::RtToBc { command, .. } => command.address,
+            Message::RtToRt {
+                receive_command, ..
+            } => receive_command.address,
+            Message::CommandStatus { command, .. } => command.address,
             Message::Status(status) => status.address,
             Message::CommandOnly(command) => command.address,
         }
     }
 
+    /// Get the command word of this message, if it has one
+    ///
+    /// For RT-to-RT transfers this is the receive command.
+    pub fn command(&self) -> Option<&Command> {
+        match self {
+            Message::BcToRt { command, .. } => Some(command),
+            Message::RtToBc { command, .. } => Some(command),
+            Message::RtToRt {
+                receive_command, ..
+            } => Some(receive_command),
+            Message::CommandStatus { command, .. } => Some(command),
+            Message::CommandOnly(command) => Some(command),
+            Message::Status(_) => None,
+        }
+    }
+
+    /// Get the total number of words in the message
+    ///
+    /// Counts command, status and data words.
+    pub fn total_words(&self) -> usize {
+        match self {
+            Message::BcToRt {
+                data_words, status, ..
+            } => 1 + data_words.len() + usize::from(status.is_some()),
+            Message::RtToBc { data_words, .. } => 2 + data_words.len(),
+            Message::RtToRt {
+                data_words,
+                receiving_status,
+                ..
+            } => 3 + data_words.len() + usize::from(receiving_status.is_some()),
+            Message::CommandStatus { .. } => 2,
+            Message::Status(_) | Message::CommandOnly(_) => 1,
+        }
+    }
+
+    /// Number of bytes [`crate::Parser::encode_message`] produces for this
+    /// message
+    ///
+    /// `gap_half_bits` is the parser's inter-word gap. Each word takes 20
+    /// Manchester pairs, each gap half as many pairs as it has half-bits,
+    /// and the last byte is padded to a whole byte.
+    pub fn encoded_len(&self, gap_half_bits: usize) -> usize {
+        let words = self.total_words();
+        let gap_pairs = words.saturating_sub(1) * (gap_half_bits / 2);
+        (words * crate::spec::WORD_LENGTH + gap_pairs).div_ceil(4)
+    }
+
+    /// Get the data words carried by the message
+    ///
+    /// Returns an empty slice for messages without data words.
+    pub fn data_words(&self) -> &[Word] {
+        match self {
+            Message::BcToRt { data_words, .. }
+            | Message::RtToBc { data_words, .. }
+            | Message::RtToRt { data_words, .. } => data_words,
+            _ => &[],
+        }
+    }
+
     /// Get the number of data words if present
     pub fn data_word_count(&self) -> Option<usize> {
         match self {
-            Message::CommandData { data_words, .. } => Some(data_words.len()),
+            Message::BcToRt { data_words, .. } => Some(data_words.len()),
+            Message::RtToBc { data_words, .. } => Some(data_words.len()),
+            Message::RtToRt { data_words, .. } => Some(data_words.len()),
+            _ => None,
+        }
+    }
+
+    /// Get the originator of the message's data words
+    ///
+    /// The parser picks the message format from the command direction, so
+    /// BC-to-RT data comes from the BC and RT-to-BC data from the RT.
+    /// Returns `None` for messages without data words.
+    pub fn data_source(&self) -> Option<DataSource> {
+        match self {
+            Message::BcToRt { .. } => Some(DataSource::Bc),
+            Message::RtToBc { .. } | Message::RtToRt { .. } => Some(DataSource::Rt),
             _ => None,
         }
     }
+
+    /// Get the words of the message in transmission order
+    ///
+    /// Command and status words are encoded from their fields; data words
+    /// are returned as stored.
+    pub fn to_words(&self) -> Result<Vec<Word>> {
+        let mut words = Vec::with_capacity(self.total_words());
+        match self {
+            Message::BcToRt {
+                command,
+                data_words,
+                status,
+            } => {
+                words.push(command.to_word()?);
+                words.extend_from_slice(data_words);
+                if let Some(status) = status {
+                    words.push(status.to_word()?);
+                }
+            }
+            Message::RtToBc {
+                command,
+                status,
+                data_words,
+            } => {
+                words.push(command.to_word()?);
+                words.push(status.to_word()?);
+                words.extend_from_slice(data_words);
+            }
+            Message::RtToRt {
+                receive_command,
+                transmit_command,
+                data_words,
+                receiving_status,
+                transmitting_status,
+            } => {
+                words.push(receive_command.to_word()?);
+                words.push(transmit_command.to_word()?);
+                words.push(transmitting_status.to_word()?);
+                words.extend_from_slice(data_words);
+                if let Some(receiving_status) = receiving_status {
+                    words.push(receiving_status.to_word()?);
+                }
+            }
+            Message::CommandStatus { command, status } => {
+                words.push(command.to_word()?);
+                words.push(status.to_word()?);
+            }
+            Message::Status(status) => words.push(status.to_word()?),
+            Message::CommandOnly(command) => words.push(command.to_word()?),
+        }
+        Ok(words)
+    }
+}
+
+impl core::fmt::Display for Message {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Message::BcToRt {
+                command,
+                data_words,
+                status: Some(status),
+            } => write!(
+                f,
+                "BC->RT {}, {} data words, {}",
+                command,
+                data_words.len(),
+                status
+            ),
+            Message::BcToRt {
+                command,
+                data_words,
+                status: None,
+            } => write!(f, "BC->RT {}, {} data words", command, data_words.len()),
+            Message::RtToBc {
+                command,
+                status,
+                data_words,
+            } => write!(
+                f,
+                "RT->BC {}, {}, {} data words",
+                command,
+                status,
+                data_words.len()
+            ),
+            Message::RtToRt {
+                receive_command,
+                transmit_command,
+                data_words,
+                ..
+            } => write!(
+                f,
+                "RT->RT {}, {}, {} data words",
+                receive_command,
+                transmit_command,
+                data_words.len()
+            ),
+            Message::CommandStatus { command, status } => write!(f, "{}, {}", command, status),
+            Message::Status(status) => write!(f, "{}", status),
+            Message::CommandOnly(command) => write!(f, "{}", command),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -377,11 +868,100 @@ mod tests {
         assert_eq!(cmd, decoded);
     }
 
+    #[test]
+    fn test_command_round_trip_all_addresses() {
+        for addr in 0..=31 {
+            let address = Address::new(addr).unwrap();
+            let sub_address = SubAddress::new(31 - addr).unwrap();
+            // Mode commands carry a mode code, which has no room for 32
+            let word_count = if matches!(31 - addr, 0 | 31) {
+                assert!(Command::new(address, CommandType::Receive, sub_address, 32).is_err());
+                17
+            } else {
+                32
+            };
+            let cmd = Command::new(address, CommandType::Receive, sub_address, word_count).unwrap();
+
+            let decoded = Command::from_word(&cmd.to_word().unwrap()).unwrap();
+            assert_eq!(cmd, decoded, "address {}", addr);
+        }
+    }
+
+    #[test]
+    fn test_mode_command_expects_status() {
+        let sync = |address| {
+            Command::new_mode_code(
+                Address::new(address).unwrap(),
+                CommandType::Transmit,
+                ModeCode::Synchronize,
+            )
+        };
+        assert!(sync(4).expects_status());
+        assert!(!sync(Address::BROADCAST).expects_status());
+    }
+
+    #[test]
+    fn test_mode_code_command() {
+        let cmd = Command::new_mode_code(
+            Address::new(4).unwrap(),
+            CommandType::Transmit,
+            ModeCode::DynamicBusControl,
+        );
+        assert!(cmd.is_mode_command());
+        assert_eq!(cmd.mode_code(), Some(ModeCode::DynamicBusControl));
+
+        // A mode code of 0 is not read back as a word count of 32
+        let decoded = Command::from_word(&cmd.to_word().unwrap()).unwrap();
+        assert_eq!(decoded, cmd);
+        assert_eq!(decoded.word_count, 0);
+
+        for value in 0..=8u8 {
+            let code = ModeCode::try_from(value).unwrap();
+            assert!(!code.has_data_word());
+            let cmd = Command::new_mode_code(Address::new(4).unwrap(), CommandType::Receive, code);
+            let decoded = Command::from_word(&cmd.to_word().unwrap()).unwrap();
+            assert_eq!(decoded.mode_code(), Some(code));
+        }
+
+        // Sub-address 31 also marks a mode command
+        let alt = Command::new(
+            Address::new(4).unwrap(),
+            CommandType::Transmit,
+            SubAddress::new(31).unwrap(),
+            2,
+        )
+        .unwrap();
+        assert_eq!(alt.mode_code(), Some(ModeCode::TransmitStatusWord));
+
+        let ordinary = Command::new(
+            Address::new(4).unwrap(),
+            CommandType::Transmit,
+            SubAddress::new(5).unwrap(),
+            2,
+        )
+        .unwrap();
+        assert_eq!(ordinary.mode_code(), None);
+    }
+
+    #[test]
+    fn test_expected_data_words() {
+        let address = Address::new(4).unwrap();
+        let command = |sa: u8, wc: u16| {
+            let sub_address = SubAddress::new(sa).unwrap();
+            Command::new(address, CommandType::Receive, sub_address, wc).unwrap()
+        };
+
+        assert_eq!(command(3, 5).expected_data_words(), 5);
+        assert_eq!(command(3, 32).expected_data_words(), 32);
+        assert_eq!(command(3, 0).expected_data_words(), 32);
+        assert_eq!(command(0, 2).expected_data_words(), 0);
+        assert_eq!(command(31, 17).expected_data_words(), 1);
+    }
+
     #[test]
     fn test_status_word_encode_decode() {
         let flags = StatusFlags::new(false, true, false, false, false);
-        // Error code limited to 7 bits (0-127) due to word structure
-        let status = StatusWord::new(Address::new(3).unwrap(), flags, 0x42).unwrap();
+        let status = StatusWord::new(Address::new(3).unwrap(), flags, 0x44).unwrap();
 
         let word = status.to_word().unwrap();
         let decoded = StatusWord::from_word(&word).unwrap();
@@ -389,12 +969,273 @@ mod tests {
         assert_eq!(status, decoded);
     }
 
+    #[test]
+    fn test_status_word_11_bit_error_code() {
+        let no_flags = StatusFlags::new(false, false, false, false, false);
+        for code in [0x100, 0x2A0, 0x7FF] {
+            let status = StatusWord::new(Address::new(3).unwrap(), no_flags, code).unwrap();
+            assert_eq!(status.error_code, code);
+
+            let decoded = StatusWord::from_word(&status.to_word().unwrap()).unwrap();
+            assert_eq!(decoded, status);
+        }
+
+        // All flag bits fall inside the 11-bit field
+        let all = StatusWord::new(Address::new(3).unwrap(), no_flags, 0x7FF).unwrap();
+        let every_flag = StatusFlags {
+            instrumentation: true,
+            service_request: true,
+            dynamic_bus_control: true,
+            terminal_flag: true,
+            ..StatusFlags::new(true, true, true, true, true)
+        };
+        assert_eq!(all.flags, every_flag);
+
+        assert!(StatusWord::new(Address::new(3).unwrap(), no_flags, 0x800).is_err());
+    }
+
+    #[test]
+    fn test_status_word_conflicting_flags() {
+        let address = Address::new(3).unwrap();
+        let busy = StatusFlags::new(false, false, true, false, false);
+
+        // Flags given once, or identically on both sides, are kept as given
+        let from_flags = StatusWord::new(address, busy, 0).unwrap();
+        assert_eq!((from_flags.flags, from_flags.error_code), (busy, 0x008));
+        let from_code = StatusWord::new(address, StatusFlags::default(), 0x008).unwrap();
+        assert_eq!(from_code, from_flags);
+        let both = StatusWord::new(address, busy, 0x068).unwrap();
+        assert_eq!(both.error_code, 0x068);
+
+        // Bits outside the flags never conflict
+        let extra = StatusWord::new(address, busy, 0x060).unwrap();
+        assert_eq!((extra.flags, extra.error_code), (busy, 0x068));
+
+        // Busy in `flags` but Message Error in `error_code`
+        assert!(StatusWord::new(address, busy, 0x400).is_err());
+        assert!(StatusWord::new(address, busy, 0x408).is_err());
+    }
+
+    #[test]
+    fn test_status_flags_round_trip_individually() {
+        // Each flag sits at its standard bit position
+        assert!(StatusFlags::decode(0x200).instrumentation);
+        assert!(StatusFlags::decode(0x100).service_request);
+        assert!(StatusFlags::decode(0x002).dynamic_bus_control);
+        assert!(StatusFlags::decode(0x001).terminal_flag);
+
+        // Bits 6 and 5 are reserved bits without a flag
+        for bit in (0..11).map(|i| 1u16 << i).filter(|bit| bit & 0x060 == 0) {
+            let flags = StatusFlags::decode(bit);
+            assert_ne!(flags, StatusFlags::default());
+
+            let status = StatusWord::new(Address::new(9).unwrap(), flags, 0).unwrap();
+            assert_eq!(status.error_code, bit);
+            let word = status.to_word().unwrap();
+            assert_eq!(word.get_data_bits() & 0x7FF, bit);
+
+            let decoded = StatusWord::from_word(&word).unwrap();
+            assert_eq!(decoded.flags, flags, "bit {:#05x}", bit);
+        }
+
+        let flags = StatusFlags::new(false, false, false, false, true);
+        assert!(flags.message_error());
+    }
+
+    #[test]
+    fn test_describe_error() {
+        let address = Address::new(6).unwrap();
+        let status = |code| StatusWord::new(address, StatusFlags::default(), code).unwrap();
+
+        assert_eq!(status(0x000).describe_error(), "No errors");
+        assert_eq!(status(0x400).describe_error(), "Message error");
+        assert_eq!(status(0x008).describe_error(), "Terminal busy");
+        assert_eq!(status(0x040).describe_error(), "Reserved bits set");
+
+        let several = status(0x405).describe_error();
+        assert_eq!(several, "Message error; Subsystem fault; Terminal fault");
+
+        let service = status(0x100).conditions();
+        assert_eq!(service, vec![StatusCondition::ServiceRequest]);
+        assert!(status(0).conditions().is_empty());
+    }
+
+    #[test]
+    fn test_status_word_round_trip_all_addresses() {
+        let no_flags = StatusFlags::new(false, false, false, false, false);
+        for addr in 0..=31 {
+            for code in [0x080, 0x1C0, 0x400, 0x7FF] {
+                let status = StatusWord::new(Address::new(addr).unwrap(), no_flags, code).unwrap();
+                let decoded = StatusWord::from_word(&status.to_word().unwrap()).unwrap();
+                assert_eq!(decoded, status, "address {} code {:#x}", addr, code);
+            }
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let command = Command::new(
+            Address::new(5).unwrap(),
+            CommandType::Transmit,
+            SubAddress::new(10).unwrap(),
+            16,
+        )
+        .unwrap();
+        assert_eq!(command.to_string(), "CMD RT-5 TX SA-10 WC-16");
+
+        let mode = Command::new_mode_code(
+            Address::new(5).unwrap(),
+            CommandType::Receive,
+            ModeCode::InitiateSelfTest,
+        );
+        assert_eq!(mode.to_string(), "CMD RT-5 RX SA-0 MC-3");
+
+        let busy = StatusFlags::new(false, false, true, false, false);
+        let status = StatusWord::new(Address::new(3).unwrap(), busy, 0).unwrap();
+        assert_eq!(status.to_string(), "STATUS RT-3 [BUSY]");
+
+        let flags = StatusFlags::new(false, true, true, false, true);
+        let several = StatusWord::new(Address::new(3).unwrap(), flags, 0).unwrap();
+        assert_eq!(several.to_string(), "STATUS RT-3 [PARITY, BUSY, SUBSYSTEM]");
+
+        let clear = StatusFlags::new(false, false, false, false, false);
+        let ok = StatusWord::new(Address::new(3).unwrap(), clear, 0).unwrap();
+        assert_eq!(ok.to_string(), "STATUS RT-3");
+
+        let data_words = vec![Word::new_unchecked(0, WordType::Data); 3];
+        let rt_to_bc = Message::RtToBc {
+            command: command.clone(),
+            status,
+            data_words,
+        };
+        assert_eq!(
+            rt_to_bc.to_string(),
+            "RT->BC CMD RT-5 TX SA-10 WC-16, STATUS RT-3 [BUSY], 3 data words"
+        );
+
+        let command_status = Message::CommandStatus {
+            command,
+            status: ok,
+        };
+        assert_eq!(
+            command_status.to_string(),
+            "CMD RT-5 TX SA-10 WC-16, STATUS RT-3"
+        );
+    }
+
+    #[test]
+    fn test_message_to_words() {
+        let command = Command::new(
+            Address::new(5).unwrap(),
+            CommandType::Transmit,
+            SubAddress::new(1).unwrap(),
+            2,
+        )
+        .unwrap();
+        let flags = StatusFlags::new(false, false, false, false, false);
+        let status = StatusWord::new(Address::new(5).unwrap(), flags, 0).unwrap();
+        let data_words = vec![
+            Word::from_data_bits(0x1234, WordType::Data),
+            Word::from_data_bits(0x5678, WordType::Data),
+        ];
+
+        let message = Message::RtToBc {
+            command: command.clone(),
+            status,
+            data_words: data_words.clone(),
+        };
+        let words = message.to_words().unwrap();
+        assert_eq!(words.len(), message.total_words());
+        assert_eq!(words[0], command.to_word().unwrap());
+        assert_eq!(words[1], status.to_word().unwrap());
+        assert_eq!(&words[2..], &data_words[..]);
+
+        let status_only = Message::Status(status).to_words().unwrap();
+        assert_eq!(status_only, vec![status.to_word().unwrap()]);
+    }
+
+    #[test]
+    fn test_message_total_words() {
+        let command = Command::new(
+            Address::new(5).unwrap(),
+            CommandType::Transmit,
+            SubAddress::new(1).unwrap(),
+            3,
+        )
+        .unwrap();
+        let status = StatusWord::new(
+            Address::new(5).unwrap(),
+            StatusFlags::new(false, false, false, false, false),
+            0,
+        )
+        .unwrap();
+        let data = vec![Word::new_unchecked(0, WordType::Data); 3];
+
+        assert_eq!(Message::CommandOnly(command.clone()).total_words(), 1);
+        assert_eq!(Message::Status(status).total_words(), 1);
+        let command_status = Message::CommandStatus {
+            command: command.clone(),
+            status,
+        };
+        assert_eq!(command_status.total_words(), 2);
+        assert_eq!(command_status.data_source(), None);
+        let bc_to_rt = Message::BcToRt {
+            command: command.clone(),
+            data_words: data.clone(),
+            status: None,
+        };
+        assert_eq!(bc_to_rt.total_words(), 4);
+        let answered = Message::BcToRt {
+            command: command.clone(),
+            data_words: data.clone(),
+            status: Some(status),
+        };
+        assert_eq!(answered.total_words(), 5);
+        let rt_to_bc = Message::RtToBc {
+            command,
+            status,
+            data_words: data,
+        };
+        assert_eq!(rt_to_bc.total_words(), 5);
+    }
+
     #[test]
     fn test_mode_code_conversion() {
-        let code: ModeCode = 1u8.try_into().unwrap();
+        let code: ModeCode = 2u8.try_into().unwrap();
         assert_eq!(code, ModeCode::TransmitStatusWord);
 
         let result: Result<ModeCode> = 99u8.try_into();
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_mode_code_full_range() {
+        let mut defined = 0;
+        for value in 0..=31u8 {
+            match ModeCode::try_from(value) {
+                Ok(code) => {
+                    defined += 1;
+                    assert_eq!(code as u8, value);
+                    assert_eq!(code.has_data_word(), value >= 16);
+                }
+                Err(_) => assert!((9..=15).contains(&value) || value >= 22),
+            }
+        }
+        assert_eq!(defined, 15);
+        assert!(ModeCode::SynchronizeWithData.has_data_word());
+        assert!(!ModeCode::Synchronize.has_data_word());
+    }
+
+    #[test]
+    fn test_mode_code_wildcard_match() {
+        let describe = |code: ModeCode| match code {
+            ModeCode::Synchronize => "sync",
+            ModeCode::TransmitStatusWord => "status",
+            _ => "other",
+        };
+
+        assert_eq!(describe(ModeCode::Synchronize), "sync");
+        assert_eq!(describe(ModeCode::TransmitStatusWord), "status");
+        assert_eq!(describe(ModeCode::ResetRemoteTerminal), "other");
+    }
 }
@@ -1,12 +1,18 @@
 //! High-level message parser for MIL-STD-1553B protocol
 
-use crate::core::{Bus, Word, WordType};
+use crate::core::{Address, Bus, SyncType, Word, WordType};
 use crate::encoding::{ManchesterDecoder, ManchesterEncoder};
 use crate::error::Result;
-use crate::message::{Command, Message, StatusWord};
+use crate::message::{Command, CommandType, Message, ModeCode, StatusWord, SubAddress};
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 /// A parsed MIL-STD-1553B transaction
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Transaction {
     /// Bus on which the transaction occurred
@@ -15,43 +21,389 @@ pub struct Transaction {
     pub message: Message,
     /// Timestamp of the transaction (microseconds, if available)
     pub timestamp_us: Option<u64>,
+    /// Gap before the RT's status response (microseconds), when per-word
+    /// timestamps are available
+    pub response_gap_us: Option<u64>,
+    /// Sequence number assigned by the capture tool, if available
+    pub sequence: Option<u32>,
+    /// Output of the data decoder registered for the sub-address, if any
+    pub decoded: Option<DecodedData>,
+}
+
+impl Transaction {
+    /// Get the 16-bit payload of each data word, in order
+    ///
+    /// Returns an empty list for messages without data words.
+    pub fn data_values(&self) -> Vec<u16> {
+        self.message
+            .data_words()
+            .iter()
+            .map(|word| word.get_data_bits())
+            .collect()
+    }
+
+    /// Check the response gap against the 4-12 µs response time window
+    ///
+    /// Returns `None` when no response gap was measured (see
+    /// [`Parser::parse_stream_with_timestamps`]).
+    pub fn check_response_time(&self) -> Option<TimingCheck> {
+        let gap_us = self.response_gap_us?;
+        let window = u64::from(crate::spec::MIN_RESPONSE_TIME_US)
+            ..=u64::from(crate::spec::MAX_RESPONSE_TIME_US);
+        Some(TimingCheck {
+            gap_us,
+            within_spec: window.contains(&gap_us),
+        })
+    }
+}
+
+/// Result of checking a transaction's response gap
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimingCheck {
+    /// Measured gap before the RT's status word, in microseconds
+    pub gap_us: u64,
+    /// Whether the gap lies within the response time window
+    pub within_spec: bool,
+}
+
+/// Application-level value decoded from a message's data words
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DecodedData {
+    /// Unsigned integer value
+    Unsigned(u64),
+    /// Signed integer value
+    Signed(i64),
+    /// Floating-point value
+    Float(f64),
+    /// Text value
+    Text(String),
+    /// Sequence of raw 16-bit values
+    Values(Vec<u16>),
+}
+
+/// Decoder for the application-specific data words of a sub-address
+pub trait DataDecoder {
+    /// Decode the data words of one message
+    fn decode(&self, words: &[Word]) -> DecodedData;
+}
+
+/// Bit ordering of the 16-bit data field in a capture
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DataBitOrder {
+    /// Most significant bit first (MIL-STD-1553B numbering)
+    #[default]
+    MsbFirst,
+    /// Least significant bit first, as used by some ICDs
+    LsbFirst,
+}
+
+/// Byte packing of Manchester-encoded captures
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CaptureLayout {
+    /// Four Manchester pairs per byte, 5 bytes per word
+    #[default]
+    Packed,
+    /// One nibble (two Manchester pairs) in the low half of each byte,
+    /// 10 bytes per word
+    NibblePerByte,
+}
+
+/// Origin of the traffic a parser is observing
+///
+/// Command and status words share the same sync pattern. The direction
+/// tells the parser which of the two a command/status sync introduces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TrafficDirection {
+    /// Words sent by the Bus Controller: command/status sync is a command
+    BcToRt,
+    /// Words sent by Remote Terminals: command/status sync is a status
+    RtToBc,
+}
+
+/// Sub-address whose first data word carries a time tag
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeTagConfig {
+    /// Sub-address reserved for the time-tag word
+    pub sub_address: SubAddress,
+    /// Duration of one time-tag count, in microseconds
+    pub resolution_us: u64,
+}
+
+impl TimeTagConfig {
+    /// Create a new time-tag configuration
+    pub fn new(sub_address: SubAddress, resolution_us: u64) -> Self {
+        TimeTagConfig {
+            sub_address,
+            resolution_us,
+        }
+    }
+
+    /// Decode the time tag carried by a message, in microseconds
+    ///
+    /// Returns `None` unless the message targets the time-tag sub-address
+    /// and carries at least one data word.
+    pub fn decode(&self, message: &Message) -> Option<u64> {
+        message
+            .command()
+            .filter(|command| command.sub_address == self.sub_address)?;
+        let word = message.data_words().first()?;
+        Some(word.get_data_bits() as u64 * self.resolution_us)
+    }
 }
 
 /// MIL-STD-1553B protocol parser
 pub struct Parser {
     /// Current bus context
     pub bus: Bus,
+    /// Bit ordering used when interpreting the 16-bit data field
+    pub bit_order: DataBitOrder,
+    /// Idle half-bit times between words, inserted when encoding and skipped
+    /// by [`Parser::parse_words`]
+    pub inter_word_gap: usize,
+    /// Byte packing of the captures being decoded
+    pub layout: CaptureLayout,
+    /// Time-tag sub-address used to timestamp parsed transactions
+    pub time_tag: Option<TimeTagConfig>,
+    /// Whether each transaction capture starts with a 4-byte big-endian
+    /// sequence number
+    pub sequence_numbers: bool,
+    /// Byte terminating each message in delimited captures
+    pub end_of_message: Option<u8>,
+    /// Data decoders, keyed by the sub-address they handle
+    pub decoders: BTreeMap<SubAddress, Box<dyn DataDecoder>>,
+    /// Whether the capture stores the two bits of each Manchester pair
+    /// swapped
+    pub pair_swapped: bool,
+    /// Whether words with an invalid sync field are rejected
+    pub strict_sync: bool,
+    /// Direction of the observed traffic, if known
+    ///
+    /// When `None`, the first command/status sync word of a message is
+    /// taken as the command and any later one as the status response.
+    pub direction: Option<TrafficDirection>,
+    /// Whether messages whose data words disagree with the command's word
+    /// count are rejected
+    pub strict_word_count: bool,
+    /// Whether words are located by their sync pulse instead of being
+    /// sliced at fixed word boundaries
+    pub sync_search: bool,
 }
 
 impl Parser {
     /// Create a new parser
     pub fn new(bus: Bus) -> Self {
-        Parser { bus }
+        Parser {
+            bus,
+            bit_order: DataBitOrder::default(),
+            inter_word_gap: 0,
+            layout: CaptureLayout::default(),
+            time_tag: None,
+            sequence_numbers: false,
+            end_of_message: None,
+            decoders: BTreeMap::new(),
+            pair_swapped: false,
+            strict_sync: false,
+            direction: None,
+            strict_word_count: false,
+            sync_search: false,
+        }
+    }
+
+    /// Register a data decoder for a sub-address
+    ///
+    /// Replaces any decoder previously registered for `sub_address`.
+    pub fn register_decoder(
+        &mut self,
+        sub_address: SubAddress,
+        decoder: impl DataDecoder + 'static,
+    ) {
+        self.decoders.insert(sub_address, Box::new(decoder));
+    }
+
+    /// Run the decoder registered for a message's sub-address
+    ///
+    /// Returns `None` for messages without a command or data words, and
+    /// for sub-addresses without a registered decoder.
+    pub fn decode_data(&self, message: &Message) -> Option<DecodedData> {
+        let decoder = self.decoders.get(&message.command()?.sub_address)?;
+        let words = message.data_words();
+        if words.is_empty() {
+            return None;
+        }
+        Some(decoder.decode(words))
     }
 
     /// Parse a single word from Manchester-encoded bytes
     ///
-    /// Expects 5 bytes (40 bits) of Manchester-encoded data representing 20 bits
+    /// Expects 5 bytes (40 bits) of Manchester-encoded data representing 20 bits,
+    /// or 10 bytes with the nibble-per-byte layout
     pub fn parse_word(&self, data: &[u8]) -> Result<Word> {
-        let word_value = ManchesterDecoder::decode_word(data)?;
-        // Try to determine word type from context or structure
-        self.identify_word_type_and_create(word_value)
+        let word_value = self.decode_word_value(&self.pack_capture(data)?)?;
+        self.identify_word_type_and_create(word_value, &mut WordTyping::default())
+    }
+
+    /// Parse a single word from Manchester-encoded bytes as a known word type
+    ///
+    /// Use this when the word type is known from protocol context.
+    pub fn parse_word_as(&self, data: &[u8], word_type: WordType) -> Result<Word> {
+        let word_value = self.decode_word_value(&self.pack_capture(data)?)?;
+        Word::new(word_value, word_type)
     }
 
     /// Parse multiple words from raw data
+    ///
+    /// The capture is sliced at fixed word boundaries, unless sync search is
+    /// enabled (see [`ParserBuilder::with_sync_search`]). With an inter-word
+    /// gap configured, the idle gap after each word is skipped, so the
+    /// output of [`Self::encode_message`] parses back.
     pub fn parse_words(&self, data: &[u8]) -> Result<Vec<Word>> {
+        let mut data = self.pack_capture(data)?;
+        if self.sync_search {
+            return self.parse_synced_words(&data);
+        }
+        if self.inter_word_gap > 0 {
+            data = Cow::Owned(ManchesterDecoder::remove_gaps(&data, self.inter_word_gap)?);
+        }
         let mut words = Vec::new();
+        let mut typing = WordTyping::default();
         let mut offset = 0;
 
         while offset + 5 <= data.len() {
-            let word = self.parse_word(&data[offset..offset + 5])?;
-            words.push(word);
+            let word_value = self.decode_word_value(&data[offset..offset + 5])?;
+            words.push(self.identify_word_type_and_create(word_value, &mut typing)?);
             offset += 5;
         }
 
         Ok(words)
     }
 
+    /// Lazily decode the words of a capture, one word-sized window at a time
+    ///
+    /// Yields the same words as [`Self::parse_words`] with fixed word
+    /// boundaries, but decodes each window only when asked for it, so
+    /// iteration can stop at the first error or any other word. A decode
+    /// error is yielded in place of its word and iteration continues with
+    /// the next window. Bytes after the last whole word are ignored.
+    pub fn words<'a>(&'a self, data: &'a [u8]) -> impl Iterator<Item = Result<Word>> + 'a {
+        let mut typing = WordTyping::default();
+        data.chunks_exact(self.word_len())
+            .map(move |bytes| self.decode_capture_word(bytes, &mut typing))
+    }
+
+    /// Parse the on-bus words of a packed capture, located by sync pulse
+    ///
+    /// Bytes that do not belong to a word, such as a partial word at the
+    /// start of the capture, are skipped.
+    fn parse_synced_words(&self, data: &[u8]) -> Result<Vec<Word>> {
+        let mut words: Vec<Word> = Vec::new();
+        let mut typing = WordTyping::default();
+        let mut offset = 0;
+
+        while let Some(start) = ManchesterDecoder::find_sync(data, offset) {
+            let Some(window) = ManchesterDecoder::word_at(data, start) else {
+                break;
+            };
+            let (data_bits, sync) = ManchesterDecoder::decode_word_with_sync(&window)?;
+            let word_type = match sync {
+                SyncType::CommandStatus => WordType::Command,
+                SyncType::Data => WordType::Data,
+            };
+            let word_value = Word::from_data_bits(data_bits, word_type).data();
+            let word_value = self.apply_bit_order(word_value);
+            words.push(self.identify_word_type_and_create(word_value, &mut typing)?);
+            offset = start + crate::spec::MANCHESTER_BITS_PER_WORD;
+        }
+
+        Ok(words)
+    }
+
+    /// Parse multiple words, skipping the ones that fail to decode
+    ///
+    /// Each word-sized window of the capture is decoded on its own, so a
+    /// bad word costs only that window and parsing resumes at the next
+    /// word boundary. Returns the words that decoded, in order, and the
+    /// byte offset and error of each window that did not, including a
+    /// trailing partial word.
+    pub fn parse_words_lossy(
+        &self,
+        data: &[u8],
+    ) -> (Vec<Word>, Vec<(usize, crate::error::ParseError)>) {
+        let word_len = self.word_len();
+        let mut words: Vec<Word> = Vec::new();
+        let mut errors = Vec::new();
+        let mut typing = WordTyping::default();
+
+        for (index, chunk) in data.chunks(word_len).enumerate() {
+            let offset = index * word_len;
+            if chunk.len() < word_len {
+                errors.push((
+                    offset,
+                    crate::error::ParseError::insufficient_data(format!(
+                        "Truncated word at byte {}",
+                        offset
+                    )),
+                ));
+                break;
+            }
+
+            match self.decode_capture_word(chunk, &mut typing) {
+                Ok(word) => words.push(word),
+                Err(err) => errors.push((offset, err)),
+            }
+        }
+
+        (words, errors)
+    }
+
+    /// Parse a capture whose messages are terminated by a marker byte
+    ///
+    /// Returns the words of each message, in order. The marker is only
+    /// recognized at word boundaries, so it should be a byte that cannot
+    /// start an encoded word (e.g. `0xFF`, which is not valid Manchester).
+    /// Bytes after the last marker are rejected as an unterminated message.
+    pub fn parse_delimited(&self, data: &[u8]) -> Result<Vec<Vec<Word>>> {
+        let marker = self.end_of_message.ok_or_else(|| {
+            crate::error::ParseError::parse_failed(
+                "No end-of-message marker configured".to_string(),
+            )
+        })?;
+        let word_len = self.word_len();
+
+        let mut messages = Vec::new();
+        let mut current = Vec::new();
+        let mut typing = WordTyping::default();
+        let mut offset = 0;
+
+        while offset < data.len() {
+            if data[offset] == marker {
+                messages.push(core::mem::take(&mut current));
+                typing = WordTyping::default();
+                offset += 1;
+            } else if offset + word_len <= data.len() {
+                let bytes = &data[offset..offset + word_len];
+                current.push(self.decode_capture_word(bytes, &mut typing)?);
+                offset += word_len;
+            } else {
+                return Err(crate::error::ParseError::insufficient_data(format!(
+                    "Truncated word at byte {}",
+                    offset
+                )));
+            }
+        }
+
+        if !current.is_empty() {
+            return Err(crate::error::ParseError::insufficient_data(
+                "Last message has no end-of-message marker".to_string(),
+            ));
+        }
+        Ok(messages)
+    }
+
     /// Parse a command-response transaction
     ///
     /// A typical transaction consists of:
@@ -59,7 +411,13 @@ impl Parser {
     /// 2. Optional data words (if receive command)
     /// 3. Status word (from Remote Terminal)
     /// 4. Optional response data words
+    ///
+    /// When a time tag is configured, a message to the time-tag
+    /// sub-address is timestamped from its first data word. When sequence
+    /// numbers are enabled, they are read from the first 4 bytes. The data
+    /// words are passed to the decoder registered for the sub-address.
     pub fn parse_transaction(&self, data: &[u8]) -> Result<Transaction> {
+        let (sequence, data) = self.split_sequence(data)?;
         let words = self.parse_words(data)?;
 
         if words.is_empty() {
@@ -68,18 +426,202 @@ impl Parser {
             ));
         }
 
-        // Identify the message structure
+        self.build_transaction(&words, sequence)
+    }
+
+    /// Lazily parse the transactions of a capture held in a borrowed slice
+    ///
+    /// Built on [`Self::words`], so a capture of any size, such as a
+    /// memory-mapped file, is decoded one word at a time and only the words
+    /// of the current message are held. Messages are split at each command
+    /// word, except the transmit command directly after a receive command,
+    /// which is the second command of an RT-to-RT transfer. Each message is
+    /// then parsed as by [`Self::parse_transaction`]; sequence numbers are
+    /// not read. A word that fails to decode is yielded as an error in
+    /// place of a transaction, and parsing resumes with the next word.
+    pub fn transactions<'a>(
+        &'a self,
+        data: &'a [u8],
+    ) -> impl Iterator<Item = Result<Transaction>> + 'a {
+        let mut words = self.words(data).peekable();
+        let mut message: Vec<Word> = Vec::new();
+
+        core::iter::from_fn(move || {
+            message.clear();
+            match words.next()? {
+                Ok(word) => message.push(word),
+                Err(err) => return Some(Err(err)),
+            }
+            while let Some(Ok(word)) = words.peek() {
+                let previous = message[message.len() - 1];
+                if word.word_type() == WordType::Command
+                    && !Self::continues_rt_to_rt(&previous, word)
+                {
+                    break;
+                }
+                message.push(*word);
+                words.next();
+            }
+            Some(self.build_transaction(&message, None))
+        })
+    }
+
+    /// Check whether `word` is the transmit command of an RT-to-RT transfer
+    /// whose receive command is `previous`
+    fn continues_rt_to_rt(previous: &Word, word: &Word) -> bool {
+        let is_command = |word: &Word, command_type| {
+            word.word_type() == WordType::Command
+                && Command::from_word(word)
+                    .is_ok_and(|command| command.command_type == command_type)
+        };
+        is_command(previous, CommandType::Receive) && is_command(word, CommandType::Transmit)
+    }
+
+    /// Build a transaction from the words of one message
+    fn build_transaction(&self, words: &[Word], sequence: Option<u32>) -> Result<Transaction> {
+        let message = self.parse_message(words)?;
+        let timestamp_us = self.time_tag.and_then(|time_tag| time_tag.decode(&message));
+        let decoded = self.decode_data(&message);
+
+        Ok(Transaction {
+            bus: self.bus,
+            message,
+            timestamp_us,
+            response_gap_us: None,
+            sequence,
+            decoded,
+        })
+    }
+
+    /// Parse a command-response transaction captured at a known time
+    ///
+    /// Like [`Self::parse_transaction`], with the transaction stamped at
+    /// `timestamp_us` instead of from its time tag.
+    pub fn parse_transaction_at(&self, data: &[u8], timestamp_us: u64) -> Result<Transaction> {
+        let mut transaction = self.parse_transaction(data)?;
+        transaction.timestamp_us = Some(timestamp_us);
+        Ok(transaction)
+    }
+
+    /// Parse a transaction from timestamped chunks of a capture
+    ///
+    /// Each chunk holds one or more whole words and the time its first word
+    /// started, in microseconds; later words of a chunk are taken to follow
+    /// back to back. The transaction is stamped with the time of its first
+    /// word, and when the message holds an RT's status word its response
+    /// gap (from the end of the word before it to the start of the status
+    /// word) is recorded. In an RT-to-RT transfer this is the transmitting
+    /// RT's status.
+    pub fn parse_stream_with_timestamps(&self, chunks: &[(u64, &[u8])]) -> Result<Transaction> {
+        let word_len = self.word_len();
+        let mut words: Vec<Word> = Vec::new();
+        let mut times = Vec::new();
+        let mut typing = WordTyping::default();
+
+        for &(timestamp_us, data) in chunks {
+            if data.len() % word_len != 0 {
+                return Err(crate::error::ParseError::insufficient_data(format!(
+                    "Chunk at {} us is not a whole number of words",
+                    timestamp_us
+                )));
+            }
+            for (index, bytes) in data.chunks(word_len).enumerate() {
+                words.push(self.decode_capture_word(bytes, &mut typing)?);
+                times.push(timestamp_us + index as u64 * crate::spec::WORD_TIME_US as u64);
+            }
+        }
+
+        if words.is_empty() {
+            return Err(crate::error::ParseError::insufficient_data(
+                "No words to parse".to_string(),
+            ));
+        }
+
         let message = self.parse_message(&words)?;
+        let response_gap_us = Self::response_index(&message).map(|index| {
+            let previous_end = times[index - 1] + crate::spec::WORD_TIME_US as u64;
+            times[index].saturating_sub(previous_end)
+        });
+        let decoded = self.decode_data(&message);
 
         Ok(Transaction {
             bus: self.bus,
             message,
-            timestamp_us: None,
+            timestamp_us: times.first().copied(),
+            response_gap_us,
+            sequence: None,
+            decoded,
         })
     }
 
+    /// Index of the first RT status word answering the message's command
+    ///
+    /// Returns `None` when the message holds no such word.
+    fn response_index(message: &Message) -> Option<usize> {
+        match message {
+            Message::RtToBc { .. } | Message::CommandStatus { .. } => Some(1),
+            Message::BcToRt {
+                data_words,
+                status: Some(_),
+                ..
+            } => Some(1 + data_words.len()),
+            Message::RtToRt { .. } => Some(2),
+            Message::BcToRt { status: None, .. } | Message::Status(_) | Message::CommandOnly(_) => {
+                None
+            }
+        }
+    }
+
+    /// Split the sequence number prefix off a transaction capture
+    fn split_sequence<'a>(&self, data: &'a [u8]) -> Result<(Option<u32>, &'a [u8])> {
+        if !self.sequence_numbers {
+            return Ok((None, data));
+        }
+        if data.len() < 4 {
+            return Err(crate::error::ParseError::insufficient_data(
+                "Missing 4-byte sequence number".to_string(),
+            ));
+        }
+        let (prefix, rest) = data.split_at(4);
+        let sequence = u32::from_be_bytes([prefix[0], prefix[1], prefix[2], prefix[3]]);
+        Ok((Some(sequence), rest))
+    }
+
     /// Parse a message from a sequence of words
     fn parse_message(&self, words: &[Word]) -> Result<Message> {
+        let message = self.identify_message(words)?;
+        if self.strict_word_count {
+            Self::check_word_count(&message)?;
+        }
+        Ok(message)
+    }
+
+    /// Check that a message carries as many data words as its command asks
+    ///
+    /// A transmit command without a status word got no response, so no
+    /// data is expected from it.
+    fn check_word_count(message: &Message) -> Result<()> {
+        let Some(command) = message.command() else {
+            return Ok(());
+        };
+        let unanswered = matches!(message, Message::CommandOnly(_));
+        if unanswered && command.command_type == CommandType::Transmit {
+            return Ok(());
+        }
+
+        let expected = command.expected_data_words();
+        let actual = message.data_words().len();
+        if actual != expected {
+            return Err(crate::error::ParseError::word_count_mismatch(format!(
+                "Command expects {} data words, got {}",
+                expected, actual
+            )));
+        }
+        Ok(())
+    }
+
+    /// Build a message from its words, without checking the word count
+    fn identify_message(&self, words: &[Word]) -> Result<Message> {
         if words.is_empty() {
             return Err(crate::error::ParseError::insufficient_data(
                 "Empty word sequence".to_string(),
@@ -91,28 +633,72 @@ impl Parser {
         match first_word.word_type() {
             WordType::Command => {
                 let command = Command::from_word(&first_word)?;
+                if command.command_type == CommandType::Receive {
+                    if let Some(message) = Self::parse_rt_to_rt(&command, &words[1..])? {
+                        return Ok(message);
+                    }
+                }
+
+                // A broadcast command is never answered, so a status word
+                // after it belongs to another message
+                let next_status = match words.get(1) {
+                    Some(word)
+                        if command.expects_status() && word.word_type() == WordType::Status =>
+                    {
+                        Some(StatusWord::from_word(word)?)
+                    }
+                    _ => None,
+                };
 
-                // Check if there are data words following
-                if words.len() > 1 {
-                    let mut data_words = Vec::new();
-                    for word in &words[1..] {
-                        if word.word_type() == WordType::Data {
-                            data_words.push(*word);
+                match (command.command_type, next_status) {
+                    // RT-to-BC: the RT answers with its status word, then data
+                    (CommandType::Transmit, Some(status)) => {
+                        let data_words = Self::collect_data_words(&words[2..]);
+                        if data_words.is_empty() {
+                            Ok(Message::CommandStatus { command, status })
                         } else {
-                            break; // Stop at non-data word
+                            Ok(Message::RtToBc {
+                                command,
+                                status,
+                                data_words,
+                            })
                         }
                     }
-
-                    if !data_words.is_empty() {
-                        Ok(Message::CommandData {
+                    (CommandType::Receive, Some(status)) => {
+                        Ok(Message::CommandStatus { command, status })
+                    }
+                    // BC-to-RT: data words follow the command directly, then
+                    // the RT answers with its status word
+                    (CommandType::Receive, None) => {
+                        let data_words = Self::collect_data_words(&words[1..]);
+                        if data_words.is_empty() {
+                            return Ok(Message::CommandOnly(command));
+                        }
+                        let status = match words.get(1 + data_words.len()) {
+                            Some(word)
+                                if command.expects_status()
+                                    && word.word_type() == WordType::Status =>
+                            {
+                                Some(StatusWord::from_word(word)?)
+                            }
+                            _ => None,
+                        };
+                        Ok(Message::BcToRt {
                             command,
                             data_words,
+                            status,
                         })
-                    } else {
-                        Ok(Message::CommandOnly(command))
                     }
-                } else {
-                    Ok(Message::CommandOnly(command))
+                    // RT-to-BC data may only follow the RT's status word
+                    (CommandType::Transmit, None) => match words.get(1) {
+                        Some(word) if word.word_type() == WordType::Data => {
+                            Err(crate::error::ParseError::invalid_response(
+                                "Transmit command data words must follow the status word"
+                                    .to_string(),
+                            ))
+                        }
+                        _ => Ok(Message::CommandOnly(command)),
+                    },
                 }
             }
             WordType::Status => {
@@ -125,14 +711,176 @@ impl Parser {
         }
     }
 
-    /// Identify word type and create a Word with appropriate type
-    fn identify_word_type_and_create(&self, word_value: u32) -> Result<Word> {
-        // Simple heuristic: analyze the word structure
-        // In a real implementation, this might be passed as a parameter
-        // or inferred from protocol context
+    /// Recognize an RT-to-RT transfer following its receive command
+    ///
+    /// The transfer is recognized when the two words after the receive
+    /// command both carry a command/status sync, the first of them decodes
+    /// as a transmit command and the second carries the address of the RT
+    /// it commands. They are read as the transmit command and the
+    /// transmitting RT's status, followed by the data words and the
+    /// receiving RT's status, which only a broadcast receive command may
+    /// lack. Only the sync is trusted here, since a configured traffic
+    /// direction types both words alike.
+    fn parse_rt_to_rt(receive_command: &Command, rest: &[Word]) -> Result<Option<Message>> {
+        let as_type = |word: &Word, word_type| Word::new_unchecked(word.data(), word_type);
+
+        let [second, third, ..] = rest else {
+            return Ok(None);
+        };
+        if second.word_type() == WordType::Data || third.word_type() == WordType::Data {
+            return Ok(None);
+        }
+        let transmit_command = Command::from_word(&as_type(second, WordType::Command))?;
+        if transmit_command.command_type != CommandType::Transmit {
+            return Ok(None);
+        }
+        let transmitting_status = StatusWord::from_word(&as_type(third, WordType::Status))?;
+        if transmitting_status.address != transmit_command.address {
+            return Ok(None);
+        }
+
+        let data_words = Self::collect_data_words(&rest[2..]);
+        let receiving_status = match rest.get(2 + data_words.len()) {
+            Some(word) if word.word_type() != WordType::Data => {
+                Some(StatusWord::from_word(&as_type(word, WordType::Status))?)
+            }
+            _ if receive_command.address.is_broadcast() => None,
+            _ => {
+                return Err(crate::error::ParseError::insufficient_data(
+                    "RT-to-RT transfer has no status word from the receiving RT".to_string(),
+                ))
+            }
+        };
+
+        Ok(Some(Message::RtToRt {
+            receive_command: receive_command.clone(),
+            transmit_command,
+            data_words,
+            receiving_status,
+            transmitting_status,
+        }))
+    }
+
+    /// Collect the leading run of data words
+    fn collect_data_words(words: &[Word]) -> Vec<Word> {
+        words
+            .iter()
+            .take_while(|word| word.word_type() == WordType::Data)
+            .copied()
+            .collect()
+    }
+
+    /// Number of capture bytes holding one word in the configured layout
+    fn word_len(&self) -> usize {
+        match self.layout {
+            CaptureLayout::Packed => 5,
+            CaptureLayout::NibblePerByte => 10,
+        }
+    }
+
+    /// Convert a capture to the packed four-pairs-per-byte representation
+    ///
+    /// With the nibble-per-byte layout, each pair of bytes is merged low
+    /// nibble first. A trailing odd byte is dropped. With pair swapping
+    /// enabled, the two bits of every Manchester pair are exchanged. A
+    /// packed capture that needs neither conversion is borrowed, not copied.
+    fn pack_capture<'a>(&self, data: &'a [u8]) -> Result<Cow<'a, [u8]>> {
+        let mut packed = self.merge_nibbles(data)?;
+        if self.pair_swapped {
+            for byte in packed.to_mut() {
+                *byte = ((*byte & 0xAA) >> 1) | ((*byte & 0x55) << 1);
+            }
+        }
+        Ok(packed)
+    }
+
+    /// Merge nibble-per-byte captures into packed bytes
+    ///
+    /// A nibble-per-byte capture must hold an even number of bytes, since
+    /// each packed byte comes from two of them.
+    fn merge_nibbles<'a>(&self, data: &'a [u8]) -> Result<Cow<'a, [u8]>> {
+        match self.layout {
+            CaptureLayout::Packed => Ok(Cow::Borrowed(data)),
+            CaptureLayout::NibblePerByte => {
+                if !data.len().is_multiple_of(2) {
+                    return Err(crate::error::ParseError::insufficient_data(format!(
+                        "Nibble-per-byte capture has an odd length of {} bytes",
+                        data.len()
+                    )));
+                }
+                if let Some(index) = data.iter().position(|&byte| byte & 0xF0 != 0) {
+                    return Err(crate::error::ParseError::invalid_manchester(format!(
+                        "Byte {} has a non-zero high nibble: 0x{:02X}",
+                        index, data[index]
+                    )));
+                }
+                Ok(Cow::Owned(
+                    data.chunks_exact(2)
+                        .map(|pair| pair[0] | (pair[1] << 4))
+                        .collect(),
+                ))
+            }
+        }
+    }
+
+    /// Decode one word-sized window of a capture in the configured layout
+    fn decode_capture_word(&self, bytes: &[u8], typing: &mut WordTyping) -> Result<Word> {
+        let word_value = self.decode_word_value(&self.pack_capture(bytes)?)?;
+        self.identify_word_type_and_create(word_value, typing)
+    }
+
+    /// Decode a packed 5-byte word and normalize its data field
+    ///
+    /// A word whose start bit (bit 0) is set is rejected as a framing error.
+    /// With strict sync checking, an invalid sync field is rejected too.
+    fn decode_word_value(&self, data: &[u8]) -> Result<u32> {
+        let word_value = ManchesterDecoder::decode_word_checked(data, self.strict_sync)?;
+        if word_value & 1 != 0 {
+            return Err(crate::error::ParseError::framing_error(format!(
+                "Start bit is set in word 0x{:05X}",
+                word_value
+            )));
+        }
+        Ok(self.apply_bit_order(word_value))
+    }
+
+    /// Normalize the data field of a raw word to MSB-first order
+    ///
+    /// Reversing the data field keeps the number of 1s unchanged, so the
+    /// parity bit stays valid.
+    fn apply_bit_order(&self, word_value: u32) -> u32 {
+        match self.bit_order {
+            DataBitOrder::MsbFirst => word_value,
+            DataBitOrder::LsbFirst => {
+                let data_bits = ((word_value >> 1) & 0xFFFF) as u16;
+                (word_value & !0x1FFFE) | ((data_bits.reverse_bits() as u32) << 1)
+            }
+        }
+    }
 
-        // For now, create as data word - caller should specify type
-        Word::new(word_value, WordType::Data)
+    /// Identify word type from the sync field and create a Word
+    ///
+    /// A data sync gives a data word. A command/status sync is resolved by
+    /// the configured [`TrafficDirection`], or else by its position in the
+    /// message, as tracked by `typing` (see [`WordTyping`]). Words without
+    /// a valid sync (only seen with strict sync checking off) are kept as
+    /// data words.
+    fn identify_word_type_and_create(
+        &self,
+        word_value: u32,
+        typing: &mut WordTyping,
+    ) -> Result<Word> {
+        let sync = SyncType::from_bits(((word_value >> 18) & 0x3) as u8);
+        let word_type = match (sync, self.direction) {
+            (Some(SyncType::CommandStatus), Some(TrafficDirection::BcToRt)) => WordType::Command,
+            (Some(SyncType::CommandStatus), Some(TrafficDirection::RtToBc)) => WordType::Status,
+            (Some(SyncType::CommandStatus), None) => typing.command_or_status(word_value),
+            (Some(SyncType::Data), _) | (None, _) => {
+                typing.data_word();
+                WordType::Data
+            }
+        };
+        Word::new(word_value, word_type)
     }
 
     /// Encode and transmit a command
@@ -149,32 +897,70 @@ impl Parser {
         Ok(encoded)
     }
 
-    /// Encode data words
-    pub fn encode_data_words(&self, data: &[u16]) -> Result<Vec<u8>> {
-        let mut encoded = Vec::new();
-
-        for &value in data {
-            let parity = Word::calculate_parity(value) as u32;
-            let word_value = (parity << 17) | ((value as u32) << 1);
-            let word = Word::new(word_value, WordType::Data)?;
+    /// Assemble a word from its 16-bit field and Manchester-encode it
+    ///
+    /// The parity bit is computed from `data_bits`, and the sync field is
+    /// set from `word_type`.
+    pub fn encode_field(&self, data_bits: u16, word_type: WordType) -> Vec<u8> {
+        ManchesterEncoder::encode_word(Word::from_data_bits(data_bits, word_type).data())
+    }
 
-            let word_encoded = ManchesterEncoder::encode_word(word.data());
-            encoded.extend(word_encoded);
-        }
+    /// Encode a complete message
+    ///
+    /// Words are emitted in transmission order, separated by the
+    /// configured inter-word gap.
+    pub fn encode_message(&self, message: &Message) -> Result<Vec<u8>> {
+        let words: Vec<u32> = message.to_words()?.iter().map(Word::data).collect();
+        ManchesterEncoder::encode_words_with_gap(&words, self.inter_word_gap)
+    }
 
-        Ok(encoded)
+    /// Encode data words
+    ///
+    /// Words are separated by the configured inter-word gap.
+    pub fn encode_data_words(&self, data: &[u16]) -> Result<Vec<u8>> {
+        let words: Vec<u32> = data
+            .iter()
+            .map(|&value| Word::from_data_bits(value, WordType::Data).data())
+            .collect();
+        ManchesterEncoder::encode_words_with_gap(&words, self.inter_word_gap)
     }
 }
 
 /// Builder for parsing MIL-STD-1553B data streams
 pub struct ParserBuilder {
     bus: Bus,
+    bit_order: DataBitOrder,
+    inter_word_gap: usize,
+    layout: CaptureLayout,
+    time_tag: Option<TimeTagConfig>,
+    sequence_numbers: bool,
+    end_of_message: Option<u8>,
+    decoders: BTreeMap<SubAddress, Box<dyn DataDecoder>>,
+    pair_swapped: bool,
+    strict_sync: bool,
+    direction: Option<TrafficDirection>,
+    strict_word_count: bool,
+    sync_search: bool,
 }
 
 impl ParserBuilder {
     /// Create a new parser builder
     pub fn new() -> Self {
-        ParserBuilder { bus: Bus::BusA }
+        ParserBuilder {
+            bus: Bus::BusA,
+            bit_order: DataBitOrder::default(),
+            inter_word_gap: 0,
+            layout: CaptureLayout::default(),
+            time_tag: None,
+            sequence_numbers: false,
+            end_of_message: None,
+            decoders: BTreeMap::new(),
+            pair_swapped: false,
+            strict_sync: false,
+            direction: None,
+            strict_word_count: false,
+            sync_search: false,
+        }
     }
 
     /// Set the bus
@@ -183,9 +969,112 @@ impl ParserBuilder {
         self
     }
 
+    /// Set the data field bit ordering
+    pub fn with_bit_order(mut self, bit_order: DataBitOrder) -> Self {
+        self.bit_order = bit_order;
+        self
+    }
+
+    /// Set the idle gap between words, in half-bit times
+    ///
+    /// The gap is inserted by the encoders and skipped by
+    /// [`Parser::parse_words`] and [`Parser::parse_transaction`]. The
+    /// word-at-a-time decoders ([`Parser::words`], [`StreamParser`],
+    /// [`Parser::parse_words_lossy`] and the like) expect gapless captures.
+    pub fn with_inter_word_gap(mut self, half_bits: usize) -> Self {
+        self.inter_word_gap = half_bits;
+        self
+    }
+
+    /// Set the byte packing of decoded captures
+    pub fn with_layout(mut self, layout: CaptureLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Timestamp transactions from the time-tag word at `sub_address`
+    ///
+    /// Each time-tag count represents `resolution_us` microseconds.
+    pub fn with_time_tag(mut self, sub_address: SubAddress, resolution_us: u64) -> Self {
+        self.time_tag = Some(TimeTagConfig::new(sub_address, resolution_us));
+        self
+    }
+
+    /// Read a 4-byte big-endian sequence number before each transaction
+    pub fn with_sequence_numbers(mut self, enabled: bool) -> Self {
+        self.sequence_numbers = enabled;
+        self
+    }
+
+    /// Set the marker byte terminating each message in delimited captures
+    pub fn with_end_of_message(mut self, marker: u8) -> Self {
+        self.end_of_message = Some(marker);
+        self
+    }
+
+    /// Register a data decoder for a sub-address
+    pub fn with_decoder(
+        mut self,
+        sub_address: SubAddress,
+        decoder: impl DataDecoder + 'static,
+    ) -> Self {
+        self.decoders.insert(sub_address, Box::new(decoder));
+        self
+    }
+
+    /// Swap the two bits of every Manchester pair before decoding
+    pub fn with_pair_swap(mut self, swapped: bool) -> Self {
+        self.pair_swapped = swapped;
+        self
+    }
+
+    /// Reject words whose sync field is not a command/status or data sync
+    pub fn with_strict_sync(mut self, strict: bool) -> Self {
+        self.strict_sync = strict;
+        self
+    }
+
+    /// Set the direction of the observed traffic
+    pub fn with_direction(mut self, direction: TrafficDirection) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Reject messages whose data words disagree with the command's word
+    /// count
+    pub fn with_strict_word_count(mut self, strict: bool) -> Self {
+        self.strict_word_count = strict;
+        self
+    }
+
+    /// Locate words by their sync pulse when parsing word streams
+    ///
+    /// The capture must hold on-bus words, as produced by
+    /// [`ManchesterEncoder::encode_word_with_sync`]. Words may start at any
+    /// half-bit, so misaligned captures and captures with leading garbage
+    /// still parse.
+    pub fn with_sync_search(mut self, enabled: bool) -> Self {
+        self.sync_search = enabled;
+        self
+    }
+
     /// Build the parser
     pub fn build(self) -> Parser {
-        Parser::new(self.bus)
+        Parser {
+            bus: self.bus,
+            bit_order: self.bit_order,
+            inter_word_gap: self.inter_word_gap,
+            layout: self.layout,
+            time_tag: self.time_tag,
+            sequence_numbers: self.sequence_numbers,
+            end_of_message: self.end_of_message,
+            decoders: self.decoders,
+            pair_swapped: self.pair_swapped,
+            strict_sync: self.strict_sync,
+            direction: self.direction,
+            strict_word_count: self.strict_word_count,
+            sync_search: self.sync_search,
+        }
     }
 }
 
@@ -195,22 +1084,285 @@ impl Default for ParserBuilder {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::core::Address;
-    use crate::message::{CommandType, SubAddress};
+/// Incremental word parser for captures that arrive in arbitrary chunks
+///
+/// Bytes are buffered until a whole word is available, so a word may be
+/// split across any number of [`StreamParser::push`] calls. Words come out
+/// exactly as [`Parser::parse_words`] would return them for the
+/// concatenated input.
+pub struct StreamParser {
+    /// Parser used to decode each word
+    parser: Parser,
+    /// Bytes received but not yet decoded
+    buffer: Vec<u8>,
+    /// Position in the current message, for typing command/status sync words
+    typing: WordTyping,
+}
 
-    #[test]
-    fn test_parser_creation() {
-        let parser = Parser::new(Bus::BusA);
-        assert_eq!(parser.bus, Bus::BusA);
+impl StreamParser {
+    /// Create a stream parser decoding with `parser`
+    pub fn new(parser: Parser) -> Self {
+        StreamParser {
+            parser,
+            buffer: Vec::new(),
+            typing: WordTyping::default(),
+        }
     }
 
-    #[test]
-    fn test_parser_builder() {
-        let parser = ParserBuilder::new().with_bus(Bus::BusB).build();
-        assert_eq!(parser.bus, Bus::BusB);
+    /// Append received bytes to the buffer
+    pub fn push(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    /// Decode the next word, if enough bytes are buffered
+    ///
+    /// The bytes of a word are consumed even when decoding fails, so an
+    /// error does not stall the stream.
+    pub fn next_word(&mut self) -> Option<Result<Word>> {
+        let word_len = self.parser.word_len();
+        if self.buffer.len() < word_len {
+            return None;
+        }
+
+        let bytes: Vec<u8> = self.buffer.drain(..word_len).collect();
+        Some(self.parser.decode_capture_word(&bytes, &mut self.typing))
+    }
+
+    /// Number of bytes buffered but not yet decoded
+    pub fn buffered(&self) -> usize {
+        self.buffer.len()
+    }
+}
+
+/// Positional typing state for command/status sync words
+///
+/// Both command and status words carry the command/status sync, so without
+/// a configured direction they are told apart by their place in the
+/// message. The state tracks which remote terminals still owe a status: a
+/// command/status word carrying one of their addresses is that status, and
+/// any other word starts a new message as its command. A transmit command
+/// directly after a receive command is the second command of an RT-to-RT
+/// transfer rather than a new message.
+#[derive(Debug, Clone, Copy, Default)]
+struct WordTyping {
+    /// Addresses of the remote terminals that still owe a status word
+    pending: [Option<u8>; 2],
+    /// Whether the previous word was a receive command
+    after_receive: bool,
+}
+
+impl WordTyping {
+    /// Type a command/status sync word and update the state
+    fn command_or_status(&mut self, word_value: u32) -> WordType {
+        let data = (word_value >> 1) & 0xFFFF;
+        let address = ((data >> 11) & 0x1F) as u8;
+        let transmit = data & 0x0400 != 0;
+
+        if let Some(slot) = self
+            .pending
+            .iter_mut()
+            .find(|pending| **pending == Some(address))
+        {
+            *slot = None;
+            self.after_receive = false;
+            return WordType::Status;
+        }
+
+        // Broadcast commands are not answered with a status
+        let owes_status = (address != crate::core::Address::BROADCAST).then_some(address);
+        if self.after_receive && transmit {
+            self.pending[1] = owes_status;
+        } else {
+            self.pending = [owes_status, None];
+        }
+        self.after_receive = !transmit;
+        WordType::Command
+    }
+
+    /// Update the state for a data word
+    fn data_word(&mut self) {
+        self.after_receive = false;
+    }
+}
+
+/// A generated capture: the transactions and their encoded byte stream
+#[derive(Debug, Clone)]
+pub struct Capture {
+    /// Transactions in capture order
+    pub transactions: Vec<Transaction>,
+    /// Manchester-encoded words of every transaction, back to back
+    pub bytes: Vec<u8>,
+}
+
+/// Builder for synthetic multi-transaction captures
+///
+/// Useful for examples and tests. Words are encoded in bus order: the
+/// command word, the BC's data words, then the RT's status and data words.
+pub struct CaptureBuilder {
+    bus: Bus,
+    transactions: Vec<Transaction>,
+}
+
+impl CaptureBuilder {
+    /// Create a new capture builder for a bus
+    pub fn new(bus: Bus) -> Self {
+        CaptureBuilder {
+            bus,
+            transactions: Vec::new(),
+        }
+    }
+
+    /// Add a BC-to-RT transfer of `data` under a receive command
+    ///
+    /// `status` is the receiving RT's answer, or `None` if it did not
+    /// respond.
+    pub fn bc_to_rt(
+        mut self,
+        timestamp_us: u64,
+        command: Command,
+        status: Option<StatusWord>,
+        data: &[u16],
+    ) -> Self {
+        self.push(
+            timestamp_us,
+            Message::BcToRt {
+                command,
+                data_words: Self::data_words(data),
+                status,
+            },
+        );
+        self
+    }
+
+    /// Add an RT-to-BC transfer of `data` under a transmit command
+    pub fn rt_to_bc(
+        mut self,
+        timestamp_us: u64,
+        command: Command,
+        status: StatusWord,
+        data: &[u16],
+    ) -> Self {
+        self.push(
+            timestamp_us,
+            Message::RtToBc {
+                command,
+                status,
+                data_words: Self::data_words(data),
+            },
+        );
+        self
+    }
+
+    /// Add a mode command to `rt` carrying `code`
+    ///
+    /// `data` is the mode code's data word, sent by the BC under a receive
+    /// command or returned by the RT after its status under a transmit
+    /// command. `status` is the RT's answer, or `None` if it did not
+    /// respond.
+    pub fn mode_command(
+        mut self,
+        timestamp_us: u64,
+        rt: Address,
+        direction: CommandType,
+        code: ModeCode,
+        status: Option<StatusWord>,
+        data: Option<u16>,
+    ) -> Self {
+        let command = Command::new_mode_code(rt, direction, code);
+        let message = match (data, status) {
+            (None, Some(status)) => Message::CommandStatus { command, status },
+            (None, None) => Message::CommandOnly(command),
+            (Some(value), Some(status)) if direction == CommandType::Transmit => Message::RtToBc {
+                command,
+                status,
+                data_words: Self::data_words(&[value]),
+            },
+            (Some(value), status) => Message::BcToRt {
+                command,
+                data_words: Self::data_words(&[value]),
+                status,
+            },
+        };
+        self.push(timestamp_us, message);
+        self
+    }
+
+    /// Build the capture
+    ///
+    /// Fails if a transfer's command direction does not match its format,
+    /// such as a transmit mode command with a data word but no status, or
+    /// if a mode command carries the wrong number of data words for its
+    /// mode code.
+    pub fn build(self) -> Result<Capture> {
+        let mut words = Vec::new();
+        for (index, transaction) in self.transactions.iter().enumerate() {
+            let Some(command) = transaction.message.command() else {
+                continue;
+            };
+            let expected = match &transaction.message {
+                Message::BcToRt { .. } => Some(CommandType::Receive),
+                Message::RtToBc { .. } => Some(CommandType::Transmit),
+                _ => None,
+            };
+            if let Some(expected) = expected.filter(|&expected| command.command_type != expected) {
+                return Err(crate::error::ParseError::invalid_command(format!(
+                    "Transaction {} uses a {:?} command for a {:?} transfer",
+                    index, command.command_type, expected
+                )));
+            }
+            let data_words = transaction.message.data_words().len();
+            if command.is_mode_command() && data_words != command.expected_data_words() {
+                return Err(crate::error::ParseError::word_count_mismatch(format!(
+                    "Transaction {} carries {} data words for mode code {}",
+                    index, data_words, command.word_count
+                )));
+            }
+
+            words.extend(transaction.message.to_words()?.iter().map(Word::data));
+        }
+
+        Ok(Capture {
+            bytes: ManchesterEncoder::encode_words_with_gap(&words, 0)?,
+            transactions: self.transactions,
+        })
+    }
+
+    /// Append a timestamped transaction on the builder's bus
+    fn push(&mut self, timestamp_us: u64, message: Message) {
+        self.transactions.push(Transaction {
+            bus: self.bus,
+            message,
+            timestamp_us: Some(timestamp_us),
+            response_gap_us: None,
+            sequence: None,
+            decoded: None,
+        });
+    }
+
+    /// Build data words with correct parity and data sync
+    fn data_words(data: &[u16]) -> Vec<Word> {
+        data.iter()
+            .map(|&value| Word::from_data_bits(value, WordType::Data))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Address;
+    use crate::message::{DataSource, StatusFlags};
+
+    #[test]
+    fn test_parser_creation() {
+        let parser = Parser::new(Bus::BusA);
+        assert_eq!(parser.bus, Bus::BusA);
+    }
+
+    #[test]
+    fn test_parser_builder() {
+        let parser = ParserBuilder::new().with_bus(Bus::BusB).build();
+        assert_eq!(parser.bus, Bus::BusB);
     }
 
     #[test]
@@ -233,10 +1385,7 @@ mod tests {
         let parser = Parser::new(Bus::BusA);
 
         // Create a word
-        let original_data = 0x12345u32;
-        let parity = Word::calculate_parity(original_data as u16) as u32;
-        let word_value = (parity << 17) | (original_data << 1);
-        let original_word = Word::new(word_value, WordType::Data)?;
+        let original_word = Word::from_data_bits(0x2345, WordType::Data);
 
         // Encode it
         let encoded = ManchesterEncoder::encode_word(original_word.data());
@@ -248,4 +1397,1112 @@ mod tests {
         assert_eq!(decoded_word.data(), original_word.data());
         Ok(())
     }
+
+    #[test]
+    fn test_parse_word_rejects_start_bit() {
+        let parser = Parser::new(Bus::BusA);
+
+        // Parity is valid over the start bit, so only the framing is wrong
+        let data_bits = 0x1234u16;
+        let parity = (Word::calculate_parity(data_bits) ^ 1) as u32;
+        let word_value = (parity << 17) | ((data_bits as u32) << 1) | 1;
+        let encoded = ManchesterEncoder::encode_word(word_value);
+
+        assert!(matches!(
+            parser.parse_word(&encoded),
+            Err(crate::error::ParseError::FramingError(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_word_strict_sync() -> Result<()> {
+        let field = 0x1234u16;
+        let parity = Word::calculate_parity(field) as u32;
+        let encode = |sync: u8| {
+            ManchesterEncoder::encode_word(
+                ((sync as u32) << 18) | (parity << 17) | ((field as u32) << 1),
+            )
+        };
+
+        let strict = ParserBuilder::new().with_strict_sync(true).build();
+        assert_eq!(
+            strict.parse_word(&encode(Word::SYNC_DATA))?.get_data_bits(),
+            field
+        );
+        assert!(strict.parse_word(&encode(0b11)).is_err());
+
+        let lenient = Parser::new(Bus::BusA);
+        assert_eq!(lenient.parse_word(&encode(0b11))?.get_data_bits(), field);
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_data_words_with_gap() -> Result<()> {
+        let parser = ParserBuilder::new().with_inter_word_gap(8).build();
+        let encoded = parser.encode_data_words(&[0x1111, 0x2222, 0x3333])?;
+
+        // Three 5-byte words separated by two idle bytes
+        assert_eq!(encoded.len(), 17);
+        assert_eq!(encoded[5], 0x00);
+        assert_eq!(encoded[11], 0x00);
+        assert_eq!(parser.parse_word(&encoded[6..11])?.get_data_bits(), 0x2222);
+
+        let values: Vec<u16> = parser
+            .parse_words(&encoded)?
+            .iter()
+            .map(|word| word.get_data_bits())
+            .collect();
+        assert_eq!(values, vec![0x1111, 0x2222, 0x3333]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_field_roundtrip() -> Result<()> {
+        let parser = Parser::new(Bus::BusA);
+
+        for (value, word_type) in [
+            (0x0000, WordType::Data),
+            (0xBEEF, WordType::Data),
+            (0x28A5, WordType::Command),
+            (0x1800, WordType::Status),
+        ] {
+            let encoded = parser.encode_field(value, word_type);
+            let word = parser.parse_word_as(&encoded, word_type)?;
+            assert_eq!(word.get_data_bits(), value);
+            assert_eq!(word.word_type(), word_type);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_lsb_first_command() -> Result<()> {
+        let command = Command::new(
+            Address::new(1)?,
+            CommandType::Receive,
+            SubAddress::new(3)?,
+            5,
+        )?;
+        let encoded = ManchesterEncoder::encode_word(command.to_word()?.data());
+
+        let decode = |bit_order| -> Result<Command> {
+            let parser = ParserBuilder::new().with_bit_order(bit_order).build();
+            let word = parser.parse_word(&encoded)?;
+            Command::from_word(&Word::new_unchecked(word.data(), WordType::Command))
+        };
+
+        let msb = decode(DataBitOrder::MsbFirst)?;
+        assert_eq!(msb, command);
+
+        // 0x0865 read LSB-first is 0xA610: RT 20, transmit, SA 16, WC 16
+        let lsb = decode(DataBitOrder::LsbFirst)?;
+        assert_eq!(lsb.address.value(), 20);
+        assert_eq!(lsb.command_type, CommandType::Transmit);
+        assert_eq!(lsb.sub_address.value(), 16);
+        assert_eq!(lsb.word_count, 16);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_nibble_per_byte_capture() -> Result<()> {
+        let encoded = Parser::new(Bus::BusA).encode_data_words(&[0x1234, 0xBEEF])?;
+        let padded: Vec<u8> = encoded
+            .iter()
+            .flat_map(|&byte| [byte & 0x0F, byte >> 4])
+            .collect();
+        assert_eq!(padded.len(), 20);
+
+        let parser = ParserBuilder::new()
+            .with_layout(CaptureLayout::NibblePerByte)
+            .build();
+        let values: Vec<u16> = parser
+            .parse_words(&padded)?
+            .iter()
+            .map(|word| word.get_data_bits())
+            .collect();
+        assert_eq!(values, vec![0x1234, 0xBEEF]);
+        assert_eq!(parser.parse_word(&padded[10..])?.get_data_bits(), 0xBEEF);
+
+        let mut bad = padded.clone();
+        bad[3] |= 0x80;
+        assert!(parser.parse_words(&bad).is_err());
+
+        // A truncated capture leaves a dangling nibble
+        assert!(matches!(
+            parser.parse_words(&padded[..19]),
+            Err(crate::error::ParseError::InsufficientData(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_capture_builder_roundtrip() -> Result<()> {
+        let rt3 = Address::new(3)?;
+        let rt5 = Address::new(5)?;
+        let receive = Command::new(rt3, CommandType::Receive, SubAddress::new(1)?, 2)?;
+        let transmit = Command::new(rt5, CommandType::Transmit, SubAddress::new(2)?, 3)?;
+        let flags = StatusFlags::new(false, false, false, false, false);
+        let status3 = StatusWord::new(rt3, flags, 0)?;
+        let status5 = StatusWord::new(rt5, flags, 0)?;
+
+        let capture = CaptureBuilder::new(Bus::BusB)
+            .bc_to_rt(0, receive.clone(), Some(status3), &[0x1111, 0x2222])
+            .rt_to_bc(500, transmit.clone(), status5, &[0xA, 0xB, 0xC])
+            .mode_command(
+                1_000,
+                rt5,
+                CommandType::Transmit,
+                ModeCode::TransmitStatusWord,
+                Some(status5),
+                None,
+            )
+            .mode_command(
+                1_500,
+                rt3,
+                CommandType::Receive,
+                ModeCode::SynchronizeWithData,
+                Some(status3),
+                Some(0x0042),
+            )
+            .mode_command(
+                2_000,
+                rt5,
+                CommandType::Transmit,
+                ModeCode::TransmitVectorWord,
+                Some(status5),
+                Some(0x00F0),
+            )
+            .mode_command(
+                2_500,
+                Address::new(Address::BROADCAST)?,
+                CommandType::Transmit,
+                ModeCode::Synchronize,
+                None,
+                None,
+            )
+            .build()?;
+        assert_eq!(capture.transactions.len(), 6);
+        assert_eq!(capture.transactions[1].timestamp_us, Some(500));
+        assert_eq!(capture.transactions[1].data_values(), vec![0xA, 0xB, 0xC]);
+
+        // Each transaction's bytes parse back to the transaction itself
+        let parser = Parser::new(Bus::BusB);
+        let mut offset = 0;
+        for expected in &capture.transactions {
+            let len = expected.message.total_words() * parser.word_len();
+            let bytes = &capture.bytes[offset..offset + len];
+            let timestamp_us = expected.timestamp_us.unwrap();
+            assert_eq!(&parser.parse_transaction_at(bytes, timestamp_us)?, expected);
+            offset += len;
+        }
+        assert_eq!(offset, capture.bytes.len());
+
+        let mismatched = CaptureBuilder::new(Bus::BusA)
+            .bc_to_rt(0, transmit, None, &[0x1])
+            .build();
+        assert!(mismatched.is_err());
+        let missing_data = CaptureBuilder::new(Bus::BusA)
+            .mode_command(
+                0,
+                rt3,
+                CommandType::Receive,
+                ModeCode::SynchronizeWithData,
+                Some(status3),
+                None,
+            )
+            .build();
+        assert!(missing_data.is_err());
+        let unanswered_transmit = CaptureBuilder::new(Bus::BusA)
+            .mode_command(
+                0,
+                rt5,
+                CommandType::Transmit,
+                ModeCode::TransmitVectorWord,
+                None,
+                Some(0x1),
+            )
+            .build();
+        assert!(unanswered_transmit.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_command_only_vs_command_status() -> Result<()> {
+        let parser = Parser::new(Bus::BusA);
+        let command = Command::new(
+            Address::new(7)?,
+            CommandType::Transmit,
+            SubAddress::new(4)?,
+            1,
+        )?;
+        let status = StatusWord::new(
+            Address::new(7)?,
+            StatusFlags::new(false, false, false, false, false),
+            0,
+        )?;
+
+        let command_only = parser.parse_message(&[command.to_word()?])?;
+        assert_eq!(command_only, Message::CommandOnly(command.clone()));
+
+        let words = [command.to_word()?, status.to_word()?];
+        let command_status = parser.parse_message(&words)?;
+        assert_eq!(
+            command_status,
+            Message::CommandStatus {
+                command: command.clone(),
+                status,
+            }
+        );
+
+        let words = [command.to_word()?, status.to_word()?, data_word(0x0042)?];
+        let with_data = parser.parse_message(&words)?;
+        assert!(matches!(with_data, Message::RtToBc { .. }));
+        Ok(())
+    }
+
+    fn data_word(value: u16) -> Result<Word> {
+        Ok(Word::from_data_bits(value, WordType::Data))
+    }
+
+    #[test]
+    fn test_parse_transaction_detects_sync() -> Result<()> {
+        let parser = Parser::new(Bus::BusA);
+        let command = Command::new(
+            Address::new(7)?,
+            CommandType::Transmit,
+            SubAddress::new(4)?,
+            2,
+        )?;
+        let flags = StatusFlags::new(false, false, false, false, false);
+        let status = StatusWord::new(Address::new(7)?, flags, 0)?;
+
+        let mut capture = parser.encode_command(&command)?;
+        capture.extend(parser.encode_status(&status)?);
+        capture.extend(parser.encode_data_words(&[0x1234, 0xABCD])?);
+
+        let transaction = parser.parse_transaction(&capture)?;
+        match &transaction.message {
+            Message::RtToBc {
+                command: parsed_command,
+                status: parsed_status,
+                ..
+            } => {
+                assert_eq!(parsed_command, &command);
+                assert_eq!(parsed_status, &status);
+            }
+            other => panic!("Expected RT-to-BC message, got {:?}", other),
+        }
+        assert_eq!(transaction.data_values(), vec![0x1234, 0xABCD]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_broadcast_mode_command_expects_no_status() -> Result<()> {
+        let parser = Parser::new(Bus::BusA);
+        let flags = StatusFlags::new(false, false, false, false, false);
+        let mode_command = |address| -> Result<Command> {
+            Ok(Command::new_mode_code(
+                Address::new(address)?,
+                CommandType::Transmit,
+                ModeCode::Synchronize,
+            ))
+        };
+
+        // Non-broadcast: the RT's status is part of the message
+        let command = mode_command(4)?;
+        let status = StatusWord::new(Address::new(4)?, flags, 0)?;
+        let words = [command.to_word()?, status.to_word()?];
+        assert_eq!(
+            parser.parse_message(&words)?,
+            Message::CommandStatus {
+                command: command.clone(),
+                status
+            }
+        );
+        let mut capture = parser.encode_command(&command)?;
+        capture.extend(parser.encode_status(&status)?);
+        assert!(matches!(
+            parser.parse_transaction(&capture)?.message,
+            Message::CommandStatus { .. }
+        ));
+
+        // Broadcast: a following status word is not an answer
+        let broadcast = mode_command(Address::BROADCAST)?;
+        let words = [broadcast.to_word()?, status.to_word()?];
+        assert_eq!(
+            parser.parse_message(&words)?,
+            Message::CommandOnly(broadcast.clone())
+        );
+        let mut capture = parser.encode_command(&broadcast)?;
+        capture.extend(parser.encode_status(&status)?);
+        assert_eq!(
+            parser.parse_transaction(&capture)?.message,
+            Message::CommandOnly(broadcast)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_transactions_over_large_slice() -> Result<()> {
+        let parser = Parser::new(Bus::BusA);
+        let flags = StatusFlags::new(false, false, false, false, false);
+        let rt3 = Address::new(3)?;
+        let rt5 = Address::new(5)?;
+        let read = Command::new(rt3, CommandType::Transmit, SubAddress::new(1)?, 2)?;
+        let receive = Command::new(rt5, CommandType::Receive, SubAddress::new(2)?, 1)?;
+        let transmit = Command::new(rt3, CommandType::Transmit, SubAddress::new(4)?, 1)?;
+
+        // An RT-to-BC read followed by an RT-to-RT transfer
+        let mut cycle = parser.encode_command(&read)?;
+        cycle.extend(parser.encode_status(&StatusWord::new(rt3, flags, 0)?)?);
+        cycle.extend(parser.encode_data_words(&[0x1111, 0x2222])?);
+        cycle.extend(parser.encode_command(&receive)?);
+        cycle.extend(parser.encode_command(&transmit)?);
+        cycle.extend(parser.encode_status(&StatusWord::new(rt3, flags, 0)?)?);
+        cycle.extend(parser.encode_data_words(&[0x3333])?);
+        cycle.extend(parser.encode_status(&StatusWord::new(rt5, flags, 0)?)?);
+
+        let cycles = 5_000;
+        let capture = cycle.repeat(cycles);
+        let mut count = 0;
+        for (index, transaction) in parser.transactions(&capture).enumerate() {
+            let transaction = transaction?;
+            if index % 2 == 0 {
+                assert!(matches!(transaction.message, Message::RtToBc { .. }));
+                assert_eq!(transaction.data_values(), vec![0x1111, 0x2222]);
+            } else {
+                assert!(matches!(transaction.message, Message::RtToRt { .. }));
+                assert_eq!(transaction.data_values(), vec![0x3333]);
+            }
+            count += 1;
+        }
+        assert_eq!(count, 2 * cycles);
+
+        // Iteration is lazy: taking the first transaction decodes only its words
+        let first = parser.transactions(&capture).next().unwrap()?;
+        assert_eq!(first.message.command(), Some(&read));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_words_back_to_back_transactions() -> Result<()> {
+        let parser = Parser::new(Bus::BusA);
+        let flags = StatusFlags::new(false, false, false, false, false);
+        let first = Command::new(
+            Address::new(7)?,
+            CommandType::Transmit,
+            SubAddress::new(4)?,
+            1,
+        )?;
+        let second = Command::new(
+            Address::new(9)?,
+            CommandType::Receive,
+            SubAddress::new(2)?,
+            1,
+        )?;
+
+        let mut capture = parser.encode_command(&first)?;
+        capture.extend(parser.encode_status(&StatusWord::new(Address::new(7)?, flags, 0)?)?);
+        capture.extend(parser.encode_data_words(&[0x1234])?);
+        capture.extend(parser.encode_command(&second)?);
+        capture.extend(parser.encode_data_words(&[0x5678])?);
+        capture.extend(parser.encode_status(&StatusWord::new(Address::new(9)?, flags, 0)?)?);
+
+        let types: Vec<WordType> = parser
+            .parse_words(&capture)?
+            .iter()
+            .map(|word| word.word_type())
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                WordType::Command,
+                WordType::Status,
+                WordType::Data,
+                WordType::Command,
+                WordType::Data,
+                WordType::Status,
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_word_traffic_direction() -> Result<()> {
+        let parser = Parser::new(Bus::BusA);
+        let sync = parser.encode_field(0x3842, WordType::Command);
+        let data = parser.encode_field(0x3842, WordType::Data);
+
+        assert_eq!(parser.parse_word(&sync)?.word_type(), WordType::Command);
+        assert_eq!(parser.parse_word(&data)?.word_type(), WordType::Data);
+
+        let from_rt = ParserBuilder::new()
+            .with_direction(TrafficDirection::RtToBc)
+            .build();
+        assert_eq!(from_rt.parse_word(&sync)?.word_type(), WordType::Status);
+        assert_eq!(from_rt.parse_word(&data)?.word_type(), WordType::Data);
+
+        // A fixed direction overrides the position within the message
+        let from_bc = ParserBuilder::new()
+            .with_direction(TrafficDirection::BcToRt)
+            .build();
+        let words = from_bc.parse_words(&[sync.clone(), sync].concat())?;
+        assert_eq!(words[0].word_type(), WordType::Command);
+        assert_eq!(words[1].word_type(), WordType::Command);
+        Ok(())
+    }
+
+    #[test]
+    fn test_stream_parser_byte_at_a_time() -> Result<()> {
+        let parser = Parser::new(Bus::BusA);
+        let command = Command::new(
+            Address::new(7)?,
+            CommandType::Transmit,
+            SubAddress::new(4)?,
+            2,
+        )?;
+        let flags = StatusFlags::new(false, true, false, false, false);
+        let status = StatusWord::new(Address::new(7)?, flags, 0)?;
+
+        let mut capture = parser.encode_command(&command)?;
+        capture.extend(parser.encode_status(&status)?);
+        capture.extend(parser.encode_data_words(&[0x1234, 0xABCD])?);
+        capture.extend([0x5A, 0xA5]);
+
+        let mut stream = StreamParser::new(Parser::new(Bus::BusA));
+        let mut streamed = Vec::new();
+        for &byte in &capture {
+            stream.push(&[byte]);
+            while let Some(word) = stream.next_word() {
+                streamed.push(word?);
+            }
+        }
+
+        assert_eq!(streamed, parser.parse_words(&capture)?);
+        assert_eq!(streamed[1].word_type(), WordType::Status);
+        // The trailing partial word stays buffered
+        assert_eq!(stream.buffered(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_words_iterator() -> Result<()> {
+        let parser = Parser::new(Bus::BusA);
+        let command = Command::new(
+            Address::new(7)?,
+            CommandType::Transmit,
+            SubAddress::new(4)?,
+            2,
+        )?;
+        let flags = StatusFlags::new(false, true, false, false, false);
+        let status = StatusWord::new(Address::new(7)?, flags, 0)?;
+
+        let mut capture = parser.encode_command(&command)?;
+        capture.extend(parser.encode_status(&status)?);
+        capture.extend(parser.encode_data_words(&[0x1234, 0xABCD])?);
+        capture.extend([0x5A, 0xA5]);
+
+        let words: Vec<Word> = parser.words(&capture).collect::<Result<_>>()?;
+        assert_eq!(words, parser.parse_words(&capture)?);
+        assert_eq!(words[1].word_type(), WordType::Status);
+
+        // A bad window yields an error in place; collecting stops there
+        let mut broken = capture[..10].to_vec();
+        broken.extend([0xFF; 5]);
+        let mut iter = parser.words(&broken);
+        assert_eq!(iter.next().transpose()?, Some(words[0]));
+        assert_eq!(iter.next().transpose()?, Some(words[1]));
+        assert!(iter.next().unwrap().is_err());
+        assert!(iter.next().is_none());
+        assert!(parser.words(&broken).collect::<Result<Vec<_>>>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_stream_parser_skips_bad_word() -> Result<()> {
+        let parser = Parser::new(Bus::BusA);
+        let good = parser.encode_field(0x1234, WordType::Data);
+
+        let mut stream = StreamParser::new(Parser::new(Bus::BusA));
+        stream.push(&[0xFF; 5]);
+        stream.push(&good[..3]);
+        assert!(matches!(stream.next_word(), Some(Err(_))));
+        assert!(stream.next_word().is_none());
+
+        stream.push(&good[3..]);
+        let word = stream.next_word().expect("complete word")?;
+        assert_eq!(word.get_data_bits(), 0x1234);
+        assert_eq!(stream.buffered(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_strict_word_count() -> Result<()> {
+        let command = Command::new(
+            Address::new(5)?,
+            CommandType::Receive,
+            SubAddress::new(2)?,
+            4,
+        )?;
+        let mut words = vec![command.to_word()?];
+        words.extend([data_word(0x1111)?, data_word(0x2222)?]);
+
+        let lenient = Parser::new(Bus::BusA);
+        assert!(matches!(
+            lenient.parse_message(&words)?,
+            Message::BcToRt { .. }
+        ));
+
+        let strict = ParserBuilder::new().with_strict_word_count(true).build();
+        assert!(matches!(
+            strict.parse_message(&words),
+            Err(crate::error::ParseError::WordCountMismatch(_))
+        ));
+
+        words.extend([data_word(0x3333)?, data_word(0x4444)?]);
+        assert!(matches!(
+            strict.parse_message(&words)?,
+            Message::BcToRt { .. }
+        ));
+
+        // A transmit command with no response expects no data
+        let transmit = Command::new(
+            Address::new(5)?,
+            CommandType::Transmit,
+            SubAddress::new(2)?,
+            4,
+        )?;
+        assert!(strict.parse_message(&[transmit.to_word()?]).is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_message_round_trip() -> Result<()> {
+        let parser = ParserBuilder::new().with_inter_word_gap(4).build();
+        let command = Command::new(
+            Address::new(12)?,
+            CommandType::Receive,
+            SubAddress::new(9)?,
+            3,
+        )?;
+        let message = Message::BcToRt {
+            command,
+            data_words: vec![data_word(0x0001)?, data_word(0xBEEF)?, data_word(0x7FFF)?],
+            status: None,
+        };
+
+        let encoded = parser.encode_message(&message)?;
+        let gapless = Parser::new(Bus::BusA).encode_message(&message)?;
+        assert_eq!(encoded.len(), gapless.len() + 2);
+        assert_eq!(message.encoded_len(0), gapless.len());
+        assert_eq!(message.encoded_len(parser.inter_word_gap), encoded.len());
+        for gap in [2, 6, 8, 10] {
+            let gapped = ParserBuilder::new().with_inter_word_gap(gap).build();
+            let expected = gapped.encode_message(&message)?.len();
+            assert_eq!(message.encoded_len(gap), expected, "gap {}", gap);
+        }
+
+        let reparsed = Parser::new(Bus::BusA).parse_transaction(&gapless)?;
+        assert_eq!(reparsed.message, message);
+
+        // The gap is only skipped by a parser configured with it
+        assert_eq!(parser.parse_transaction(&encoded)?.message, message);
+        assert!(Parser::new(Bus::BusA).parse_transaction(&encoded).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_rt_to_rt_message() -> Result<()> {
+        let receive_command = Command::new(
+            Address::new(3)?,
+            CommandType::Receive,
+            SubAddress::new(6)?,
+            2,
+        )?;
+        let transmit_command = Command::new(
+            Address::new(9)?,
+            CommandType::Transmit,
+            SubAddress::new(11)?,
+            2,
+        )?;
+        let flags = StatusFlags::new(false, false, false, false, false);
+        let message = Message::RtToRt {
+            receive_command,
+            transmit_command: transmit_command.clone(),
+            data_words: vec![data_word(0xCAFE)?, data_word(0x0042)?],
+            receiving_status: Some(StatusWord::new(Address::new(3)?, flags, 0)?),
+            transmitting_status: StatusWord::new(Address::new(9)?, flags, 0)?,
+        };
+
+        let parser = Parser::new(Bus::BusA);
+        assert_eq!(parser.parse_message(&message.to_words()?)?, message);
+
+        let transaction = parser.parse_transaction(&parser.encode_message(&message)?)?;
+        assert_eq!(transaction.message, message);
+        assert_eq!(transaction.message.address().value(), 3);
+
+        // Without the receiving RT's status the transfer is incomplete
+        let words = message.to_words()?;
+        assert!(parser.parse_message(&words[..words.len() - 1]).is_err());
+
+        // Broadcast receivers do not answer
+        let broadcast = Message::RtToRt {
+            receive_command: Command::new(
+                Address::broadcast(),
+                CommandType::Receive,
+                SubAddress::new(6)?,
+                2,
+            )?,
+            transmit_command,
+            data_words: vec![data_word(0xCAFE)?, data_word(0x0042)?],
+            receiving_status: None,
+            transmitting_status: StatusWord::new(Address::new(9)?, flags, 0)?,
+        };
+        let encoded = parser.encode_message(&broadcast)?;
+        assert_eq!(parser.parse_transaction(&encoded)?.message, broadcast);
+        Ok(())
+    }
+
+    #[test]
+    fn test_message_error_status_is_not_rt_to_rt() -> Result<()> {
+        let parser = Parser::new(Bus::BusA);
+        let receive = Command::new(
+            Address::new(3)?,
+            CommandType::Receive,
+            SubAddress::new(6)?,
+            1,
+        )?;
+        // Message Error sets the bit a command uses for transmit
+        let status =
+            StatusWord::from_word(&Word::from_data_bits((3 << 11) | 0x400, WordType::Status))?;
+        let next = Command::new(
+            Address::new(9)?,
+            CommandType::Transmit,
+            SubAddress::new(2)?,
+            1,
+        )?;
+
+        let words = [receive.to_word()?, status.to_word()?, next.to_word()?];
+        assert_eq!(
+            parser.parse_message(&words)?,
+            Message::CommandStatus {
+                command: receive,
+                status,
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_words_lossy() -> Result<()> {
+        let parser = Parser::new(Bus::BusA);
+        let mut capture = parser.encode_data_words(&[0x1111, 0x2222])?;
+        // An invalid Manchester pattern in the third word
+        capture.extend([0x00; 5]);
+        capture.extend(parser.encode_data_words(&[0x3333])?);
+
+        assert!(parser.parse_words(&capture).is_err());
+
+        let (words, errors) = parser.parse_words_lossy(&capture);
+        let values: Vec<u16> = words.iter().map(Word::get_data_bits).collect();
+        assert_eq!(values, vec![0x1111, 0x2222, 0x3333]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, 10);
+        assert!(matches!(
+            errors[0].1,
+            crate::error::ParseError::InvalidManchesterEncoding(_)
+        ));
+
+        // A trailing partial word is reported, not dropped
+        capture.extend([0x5A, 0xA5]);
+        let (words, errors) = parser.parse_words_lossy(&capture);
+        assert_eq!(words.len(), 3);
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[1].0, 20);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_bc_to_rt_message() -> Result<()> {
+        let parser = Parser::new(Bus::BusA);
+        let command = Command::new(
+            Address::new(5)?,
+            CommandType::Receive,
+            SubAddress::new(2)?,
+            2,
+        )?;
+        let words = vec![command.to_word()?, data_word(0x1111)?, data_word(0x2222)?];
+
+        let message = parser.parse_message(&words)?;
+        assert_eq!(
+            message,
+            Message::BcToRt {
+                command: command.clone(),
+                data_words: words[1..].to_vec(),
+                status: None,
+            }
+        );
+        assert_eq!(message.data_source(), Some(DataSource::Bc));
+
+        let status = StatusWord::new(
+            Address::new(5)?,
+            StatusFlags::new(false, false, false, false, false),
+            0,
+        )?;
+        let mut answered = words.clone();
+        answered.push(status.to_word()?);
+        let message = parser.parse_message(&answered)?;
+        assert_eq!(
+            message,
+            Message::BcToRt {
+                command,
+                data_words: words[1..].to_vec(),
+                status: Some(status),
+            }
+        );
+        assert_eq!(message.to_words()?, answered);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_pair_swapped_capture() -> Result<()> {
+        let encoded = Parser::new(Bus::BusA).encode_data_words(&[0x1234, 0x00FF])?;
+        let swapped: Vec<u8> = encoded
+            .iter()
+            .map(|&byte| ((byte & 0xAA) >> 1) | ((byte & 0x55) << 1))
+            .collect();
+
+        let parser = ParserBuilder::new().with_pair_swap(true).build();
+        let values: Vec<u16> = parser
+            .parse_words(&swapped)?
+            .iter()
+            .map(|word| word.get_data_bits())
+            .collect();
+        assert_eq!(values, vec![0x1234, 0x00FF]);
+
+        // Read as-is, every bit comes out inverted and fails the framing check
+        assert!(Parser::new(Bus::BusA).parse_words(&swapped).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_delimited() -> Result<()> {
+        let parser = ParserBuilder::new().with_end_of_message(0xFF).build();
+        let mut capture = Vec::new();
+        capture.extend(parser.encode_data_words(&[0x1111, 0x2222])?);
+        capture.push(0xFF);
+        capture.extend(parser.encode_data_words(&[0x3333])?);
+        capture.push(0xFF);
+        capture.extend(parser.encode_data_words(&[0x4444, 0x5555, 0x6666])?);
+        capture.push(0xFF);
+
+        let grouped: Vec<Vec<u16>> = parser
+            .parse_delimited(&capture)?
+            .iter()
+            .map(|words| words.iter().map(|word| word.get_data_bits()).collect())
+            .collect();
+        assert_eq!(
+            grouped,
+            vec![
+                vec![0x1111, 0x2222],
+                vec![0x3333],
+                vec![0x4444, 0x5555, 0x6666]
+            ]
+        );
+
+        assert!(parser
+            .parse_delimited(&capture[..capture.len() - 1])
+            .is_err());
+        assert!(Parser::new(Bus::BusA).parse_delimited(&capture).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_sequence() -> Result<()> {
+        let capture = [0x00, 0x00, 0x01, 0x02, 0xAA, 0x55];
+
+        let parser = ParserBuilder::new().with_sequence_numbers(true).build();
+        let (sequence, rest) = parser.split_sequence(&capture)?;
+        assert_eq!(sequence, Some(0x0102));
+        assert_eq!(rest, &capture[4..]);
+        assert!(parser.split_sequence(&capture[..3]).is_err());
+
+        let (sequence, rest) = Parser::new(Bus::BusA).split_sequence(&capture)?;
+        assert_eq!(sequence, None);
+        assert_eq!(rest, &capture[..]);
+        Ok(())
+    }
+
+    struct SumDecoder;
+
+    impl DataDecoder for SumDecoder {
+        fn decode(&self, words: &[Word]) -> DecodedData {
+            DecodedData::Unsigned(words.iter().map(|word| word.get_data_bits() as u64).sum())
+        }
+    }
+
+    #[test]
+    fn test_data_decoder_registry() -> Result<()> {
+        let parser = ParserBuilder::new()
+            .with_decoder(SubAddress::new(4)?, SumDecoder)
+            .build();
+        let message = |sa| -> Result<Message> {
+            let command = Command::new(
+                Address::new(5)?,
+                CommandType::Receive,
+                SubAddress::new(sa)?,
+                3,
+            )?;
+            parser.parse_message(&[
+                command.to_word()?,
+                data_word(1)?,
+                data_word(20)?,
+                data_word(300)?,
+            ])
+        };
+
+        assert_eq!(
+            parser.decode_data(&message(4)?),
+            Some(DecodedData::Unsigned(321))
+        );
+        assert_eq!(parser.decode_data(&message(5)?), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_time_tag() -> Result<()> {
+        let parser = Parser::new(Bus::BusA);
+        let time_tag = TimeTagConfig::new(SubAddress::new(30)?, 64);
+        let message = |sa| -> Result<Message> {
+            let command = Command::new(
+                Address::new(5)?,
+                CommandType::Receive,
+                SubAddress::new(sa)?,
+                1,
+            )?;
+            parser.parse_message(&[command.to_word()?, data_word(0x0100)?])
+        };
+
+        // 0x100 counts of 64 us
+        assert_eq!(time_tag.decode(&message(30)?), Some(16_384));
+        assert_eq!(time_tag.decode(&message(1)?), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_transaction_data_values() -> Result<()> {
+        let command = Command::new(
+            Address::new(5)?,
+            CommandType::Receive,
+            SubAddress::new(2)?,
+            3,
+        )?;
+        let transaction = Transaction {
+            bus: Bus::BusA,
+            message: Message::BcToRt {
+                command,
+                data_words: vec![data_word(0x0001)?, data_word(0xABCD)?, data_word(0xFFFF)?],
+                status: None,
+            },
+            timestamp_us: None,
+            response_gap_us: None,
+            sequence: None,
+            decoded: None,
+        };
+        assert_eq!(transaction.data_values(), vec![0x0001, 0xABCD, 0xFFFF]);
+
+        let status = StatusWord::new(
+            Address::new(5)?,
+            StatusFlags::new(false, false, false, false, false),
+            0,
+        )?;
+        let status_only = Transaction {
+            message: Message::Status(status),
+            ..transaction
+        };
+        assert!(status_only.data_values().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_rt_to_bc_message() -> Result<()> {
+        let parser = Parser::new(Bus::BusA);
+        let command = Command::new(
+            Address::new(5)?,
+            CommandType::Transmit,
+            SubAddress::new(2)?,
+            1,
+        )?;
+        let status = StatusWord::new(
+            Address::new(5)?,
+            StatusFlags::new(false, false, false, false, false),
+            0,
+        )?;
+        let words = vec![command.to_word()?, status.to_word()?, data_word(0xBEEF)?];
+
+        let message = parser.parse_message(&words)?;
+        assert_eq!(
+            message,
+            Message::RtToBc {
+                command,
+                status,
+                data_words: vec![words[2]],
+            }
+        );
+        assert_eq!(message.data_source(), Some(DataSource::Rt));
+
+        // Data ahead of the status word is out of order
+        let reordered = vec![words[0], words[2], words[1]];
+        assert!(matches!(
+            parser.parse_message(&reordered),
+            Err(crate::error::ParseError::InvalidResponse(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_words_sync_search() -> Result<()> {
+        let parser = ParserBuilder::new().with_sync_search(true).build();
+        let command = Command::new(
+            Address::new(4)?,
+            CommandType::Receive,
+            SubAddress::new(3)?,
+            2,
+        )?;
+
+        // Leading garbage that is not a multiple of the word length
+        let mut capture = vec![0xFF, 0x00, 0x5A];
+        let command_bits = command.to_word()?.get_data_bits();
+        capture.extend(ManchesterEncoder::encode_word_with_sync(
+            command_bits,
+            SyncType::CommandStatus,
+        ));
+        for value in [0x1234, 0xABCD] {
+            let word = ManchesterEncoder::encode_word_with_sync(value, SyncType::Data);
+            capture.extend(word);
+        }
+
+        let words = parser.parse_words(&capture)?;
+        assert_eq!(words.len(), 3);
+        assert_eq!(words[0].word_type(), WordType::Command);
+
+        let transaction = parser.parse_transaction(&capture)?;
+        assert_eq!(transaction.message.command(), Some(&command));
+        assert_eq!(transaction.data_values(), vec![0x1234, 0xABCD]);
+
+        // Fixed slicing cannot find the words
+        assert!(Parser::new(Bus::BusA).parse_words(&capture).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_stream_with_timestamps() -> Result<()> {
+        let parser = Parser::new(Bus::BusA);
+        let command = Command::new(
+            Address::new(7)?,
+            CommandType::Transmit,
+            SubAddress::new(2)?,
+            2,
+        )?;
+        let status = StatusWord::new(
+            Address::new(7)?,
+            StatusFlags::new(false, false, false, false, false),
+            0,
+        )?;
+        let command_bytes = parser.encode_command(&command)?;
+        let status_bytes = parser.encode_status(&status)?;
+        let data_bytes = parser.encode_data_words(&[0x1234, 0xABCD])?;
+
+        // The command ends at 1020 us and the status starts 9 us later
+        let transaction = parser.parse_stream_with_timestamps(&[
+            (1000, &command_bytes),
+            (1029, &status_bytes),
+            (1049, &data_bytes),
+        ])?;
+        assert_eq!(transaction.timestamp_us, Some(1000));
+        assert_eq!(transaction.response_gap_us, Some(9));
+        assert_eq!(transaction.data_values(), vec![0x1234, 0xABCD]);
+
+        // Without a status word there is no response gap
+        let receive = Command::new(
+            Address::new(7)?,
+            CommandType::Receive,
+            SubAddress::new(2)?,
+            2,
+        )?;
+        let receive_bytes = parser.encode_command(&receive)?;
+        let transaction =
+            parser.parse_stream_with_timestamps(&[(50, &receive_bytes), (70, &data_bytes)])?;
+        assert_eq!(transaction.response_gap_us, None);
+        assert!(parser
+            .parse_stream_with_timestamps(&[(0, &command_bytes[..3])])
+            .is_err());
+
+        // In an RT-to-RT transfer the gap is the transmitting RT's, not the
+        // one between the two commands
+        let transmit_status = StatusWord::new(
+            Address::new(9)?,
+            StatusFlags::new(false, false, false, false, false),
+            0,
+        )?;
+        let transmit = Command::new(
+            Address::new(9)?,
+            CommandType::Transmit,
+            SubAddress::new(4)?,
+            2,
+        )?;
+        let transaction = parser.parse_stream_with_timestamps(&[
+            (100, &receive_bytes),
+            (122, &parser.encode_command(&transmit)?),
+            (148, &parser.encode_status(&transmit_status)?),
+            (168, &data_bytes),
+            (215, &status_bytes),
+        ])?;
+        assert!(matches!(transaction.message, Message::RtToRt { .. }));
+        assert_eq!(transaction.response_gap_us, Some(6));
+
+        let capture = [command_bytes, status_bytes, data_bytes].concat();
+        let stamped = parser.parse_transaction_at(&capture, 2000)?;
+        assert_eq!(stamped.check_response_time(), None);
+        assert_eq!(stamped.timestamp_us, Some(2000));
+        assert_eq!(stamped.response_gap_us, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_response_time() -> Result<()> {
+        let parser = Parser::new(Bus::BusA);
+        let command = Command::new(
+            Address::new(4)?,
+            CommandType::Transmit,
+            SubAddress::new(1)?,
+            1,
+        )?;
+        let status = StatusWord::new(
+            Address::new(4)?,
+            StatusFlags::new(false, false, false, false, false),
+            0,
+        )?;
+        let command_bytes = parser.encode_command(&command)?;
+        let status_bytes = parser.encode_status(&status)?;
+
+        // The command ends at 20 us
+        let check = |status_at: u64| -> Result<Option<TimingCheck>> {
+            let transaction = parser
+                .parse_stream_with_timestamps(&[(0, &command_bytes), (status_at, &status_bytes)])?;
+            Ok(transaction.check_response_time())
+        };
+        let in_window = TimingCheck {
+            gap_us: 8,
+            within_spec: true,
+        };
+        assert_eq!(check(28)?, Some(in_window));
+        assert_eq!(check(24)?.map(|check| check.within_spec), Some(true));
+        assert_eq!(check(32)?.map(|check| check.within_spec), Some(true));
+
+        let too_short = check(22)?.unwrap();
+        assert_eq!((too_short.gap_us, too_short.within_spec), (2, false));
+        let too_long = check(35)?.unwrap();
+        assert_eq!((too_long.gap_us, too_long.within_spec), (15, false));
+        Ok(())
+    }
 }
@@ -1,9 +1,17 @@
 //! Protocol-level handling and validation for MIL-STD-1553B
 
-use crate::core::{Address, Bus};
-use crate::error::Result;
-use std::collections::HashMap;
-use std::time::{Duration, Instant};
+use crate::core::{Address, Bus, Word, WordType};
+use crate::error::{ParseError, Result};
+use crate::message::{Command, CommandType, Message, ModeCode, StatusWord};
+use crate::parser::Transaction;
+use alloc::collections::{BTreeMap, VecDeque};
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::Instant;
 
 /// State of a Remote Terminal device
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -27,23 +35,43 @@ pub struct RemoteTerminal {
     pub address: Address,
     /// Current state
     pub state: RTState,
-    /// Last communication time
+    /// Last communication time (not serialized, as `Instant` is process-local)
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub last_seen: Option<Instant>,
     /// Number of errors detected
     pub error_count: u32,
     /// Number of successful transactions
     pub success_count: u32,
+    /// Most recent error events as `(timestamp_us, error)`, oldest first
+    #[cfg_attr(feature = "serde", serde(skip))]
+    error_log: VecDeque<(u64, ParseError)>,
+    /// Whether the RT answered each of its most recent commands, oldest first
+    #[cfg_attr(feature = "serde", serde(skip))]
+    recent_responses: VecDeque<bool>,
+    /// Mode codes the RT has accepted, in code order
+    #[cfg_attr(feature = "serde", serde(skip))]
+    accepted_mode_codes: Vec<ModeCode>,
 }
 
 impl RemoteTerminal {
+    /// Maximum number of error events retained in the error log
+    pub const ERROR_LOG_CAPACITY: usize = 64;
+    /// Number of recent commands covered by the response rate
+    pub const RESPONSE_WINDOW: usize = 32;
+
     /// Create a new Remote Terminal info
     pub fn new(address: Address) -> Self {
         RemoteTerminal {
             address,
             state: RTState::Idle,
+            #[cfg(feature = "std")]
             last_seen: None,
             error_count: 0,
             success_count: 0,
+            error_log: VecDeque::new(),
+            recent_responses: VecDeque::new(),
+            accepted_mode_codes: Vec::new(),
         }
     }
 
@@ -51,23 +79,128 @@ impl RemoteTerminal {
     pub fn record_success(&mut self) {
         self.success_count += 1;
         self.state = RTState::Idle;
-        self.last_seen = Some(Instant::now());
+        #[cfg(feature = "std")]
+        {
+            self.last_seen = Some(Instant::now());
+        }
+        self.push_response(true);
+    }
+
+    /// Record a command the RT did not answer
+    pub fn record_no_response(&mut self) {
+        self.state = RTState::NoResponse;
+        self.push_response(false);
+    }
+
+    /// Fraction of the last [`Self::RESPONSE_WINDOW`] commands the RT answered
+    ///
+    /// Returns 0.0 when no commands have been recorded.
+    pub fn response_rate(&self) -> f32 {
+        if self.recent_responses.is_empty() {
+            return 0.0;
+        }
+        let answered = self.recent_responses.iter().filter(|&&r| r).count();
+        answered as f32 / self.recent_responses.len() as f32
+    }
+
+    /// Append to the response window, dropping the oldest entry when full
+    fn push_response(&mut self, responded: bool) {
+        if self.recent_responses.len() == Self::RESPONSE_WINDOW {
+            self.recent_responses.pop_front();
+        }
+        self.recent_responses.push_back(responded);
     }
 
     /// Record a failed transaction
     pub fn record_error(&mut self) {
         self.error_count += 1;
         self.state = RTState::Error;
-        self.last_seen = Some(Instant::now());
+        #[cfg(feature = "std")]
+        {
+            self.last_seen = Some(Instant::now());
+        }
+    }
+
+    /// Record a failed transaction along with when and why it failed
+    ///
+    /// The oldest event is dropped once the log holds
+    /// [`Self::ERROR_LOG_CAPACITY`] entries.
+    pub fn record_error_event(&mut self, timestamp_us: u64, error: ParseError) {
+        self.record_error();
+        if self.error_log.len() == Self::ERROR_LOG_CAPACITY {
+            self.error_log.pop_front();
+        }
+        self.error_log.push_back((timestamp_us, error));
+    }
+
+    /// Get the recorded error events, oldest first
+    pub fn error_log(&self) -> &VecDeque<(u64, ParseError)> {
+        &self.error_log
+    }
+
+    /// Record that the RT accepted a mode code
+    pub fn accept_mode_code(&mut self, code: ModeCode) {
+        if let Err(position) = self
+            .accepted_mode_codes
+            .binary_search_by_key(&(code as u8), |&accepted| accepted as u8)
+        {
+            self.accepted_mode_codes.insert(position, code);
+        }
+    }
+
+    /// Get the mode codes the RT has accepted, in code order
+    pub fn accepted_mode_codes(&self) -> &[ModeCode] {
+        &self.accepted_mode_codes
     }
 
     /// Check if device is responding (seen within timeout)
+    #[cfg(feature = "std")]
     pub fn is_responding(&self, timeout: Duration) -> bool {
         match self.last_seen {
             Some(instant) => instant.elapsed() < timeout,
             None => false,
         }
     }
+
+    /// Fold another observation of the same RT into this one
+    ///
+    /// Counts are summed, accepted mode codes are combined, and the state
+    /// of the most recently seen side is kept. Without `std` there is no `last_seen` to compare, so `other`
+    /// is taken as the more recent side whenever it has recorded anything.
+    /// Error logs are merged by timestamp, keeping the newest
+    /// [`Self::ERROR_LOG_CAPACITY`] events.
+    fn merge(&mut self, other: &RemoteTerminal) {
+        self.success_count += other.success_count;
+        self.error_count += other.error_count;
+        #[cfg(feature = "std")]
+        let other_is_newer = other.last_seen > self.last_seen;
+        #[cfg(not(feature = "std"))]
+        let other_is_newer =
+            other.success_count + other.error_count > 0 || !other.recent_responses.is_empty();
+        if other_is_newer {
+            #[cfg(feature = "std")]
+            {
+                self.last_seen = other.last_seen;
+            }
+            self.state = other.state;
+        }
+
+        let mut events: Vec<(u64, ParseError)> = self
+            .error_log
+            .drain(..)
+            .chain(other.error_log.iter().cloned())
+            .collect();
+        events.sort_by_key(|(timestamp_us, _)| *timestamp_us);
+        let excess = events.len().saturating_sub(Self::ERROR_LOG_CAPACITY);
+        self.error_log = events.into_iter().skip(excess).collect();
+
+        for &responded in &other.recent_responses {
+            self.push_response(responded);
+        }
+        for &code in &other.accepted_mode_codes {
+            self.accept_mode_code(code);
+        }
+    }
 }
 
 /// Bus Controller state and management
@@ -76,7 +209,7 @@ pub struct BusController {
     /// Bus identifier
     pub bus: Bus,
     /// Remote terminals on this bus
-    remote_terminals: HashMap<u8, RemoteTerminal>,
+    remote_terminals: BTreeMap<u8, RemoteTerminal>,
     /// Expected response timeout
     pub response_timeout: Duration,
 }
@@ -86,7 +219,7 @@ impl BusController {
     pub fn new(bus: Bus) -> Self {
         BusController {
             bus,
-            remote_terminals: HashMap::new(),
+            remote_terminals: BTreeMap::new(),
             response_timeout: Duration::from_micros(12), // Typical 12 microseconds
         }
     }
@@ -127,6 +260,7 @@ impl BusController {
     }
 
     /// Get all responding Remote Terminals
+    #[cfg(feature = "std")]
     pub fn get_responding_rts(&self) -> Vec<&RemoteTerminal> {
         self.remote_terminals
             .values()
@@ -134,6 +268,118 @@ impl BusController {
             .collect()
     }
 
+    /// Get RTs not seen within `threshold`, including those never seen
+    ///
+    /// Addresses are returned in ascending order.
+    #[cfg(feature = "std")]
+    pub fn stale_rts(&self, threshold: Duration) -> Vec<Address> {
+        let mut stale: Vec<Address> = self
+            .remote_terminals
+            .values()
+            .filter(|rt| !rt.is_responding(threshold))
+            .map(|rt| rt.address)
+            .collect();
+        stale.sort();
+        stale
+    }
+
+    /// Check whether a transaction's status response came too late
+    ///
+    /// Compares the measured response gap against the response timeout.
+    /// Transactions without a measured gap are never late.
+    pub fn is_late_response(&self, transaction: &Transaction) -> bool {
+        transaction
+            .response_gap_us
+            .is_some_and(|gap| u128::from(gap) > self.response_timeout.as_micros())
+    }
+
+    /// Update RT state from a parsed transaction
+    ///
+    /// A commanded RT is credited with a success when a status word with its
+    /// address answers within the response timeout. A missing or late status
+    /// records a no-response and a status from another address an error.
+    /// The measured response gap covers the first status word only.
+    /// Broadcast commands, which are not answered, and status-only messages
+    /// are ignored. Fails if a commanded RT is not registered.
+    pub fn observe_transaction(&mut self, transaction: &Transaction) -> Result<()> {
+        let exchanges: Vec<(&Command, Option<&StatusWord>)> = match &transaction.message {
+            Message::RtToBc {
+                command, status, ..
+            }
+            | Message::CommandStatus { command, status } => vec![(command, Some(status))],
+            Message::BcToRt {
+                command, status, ..
+            } => vec![(command, status.as_ref())],
+            Message::RtToRt {
+                receive_command,
+                transmit_command,
+                receiving_status,
+                transmitting_status,
+                ..
+            } => vec![
+                (transmit_command, Some(transmitting_status)),
+                (receive_command, receiving_status.as_ref()),
+            ],
+            Message::CommandOnly(command) => vec![(command, None)],
+            Message::Status(_) => Vec::new(),
+        };
+
+        for (index, (command, status)) in exchanges.into_iter().enumerate() {
+            if command.address.is_broadcast() {
+                continue;
+            }
+            let late = index == 0 && self.is_late_response(transaction);
+            match status {
+                Some(status) if status.address != command.address => {
+                    self.record_rt_error(command.address)?
+                }
+                Some(status) if !late => {
+                    self.record_rt_success(command.address)?;
+                    if let (Some(code), false) = (command.mode_code(), status.flags.parity_error) {
+                        if let Some(rt) = self.get_rt_mut(command.address) {
+                            rt.accept_mode_code(code);
+                        }
+                    }
+                }
+                _ => self.record_rt_no_response(command.address)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Build the poll that follows up a service request
+    ///
+    /// An RT asks for service by setting Service Request in its status
+    /// word; the BC then sends it a Transmit Vector Word mode command to
+    /// learn what it needs. Returns that command, or `None` if the status
+    /// does not request service.
+    pub fn service_request_poll(&self, status: &StatusWord) -> Option<Command> {
+        status.flags.service_request.then(|| {
+            Command::new_mode_code(
+                status.address,
+                CommandType::Transmit,
+                ModeCode::TransmitVectorWord,
+            )
+        })
+    }
+
+    /// Merge RT statistics collected by another controller
+    ///
+    /// Success and error counts are summed per RT, and the state of the
+    /// most recently seen side is kept. Without `std`, the state from
+    /// `other` is kept for every RT it has recorded anything for. RTs known
+    /// only to `other` are added.
+    pub fn merge_stats(&mut self, other: &BusController) {
+        for (&address, rt) in &other.remote_terminals {
+            match self.remote_terminals.get_mut(&address) {
+                Some(existing) => existing.merge(rt),
+                None => {
+                    self.remote_terminals.insert(address, rt.clone());
+                }
+            }
+        }
+    }
+
     /// Get total number of RTs
     pub fn rt_count(&self) -> usize {
         self.remote_terminals.len()
@@ -163,6 +409,66 @@ impl BusController {
         }
     }
 
+    /// Record a command that an RT did not answer
+    pub fn record_rt_no_response(&mut self, address: Address) -> Result<()> {
+        if let Some(rt) = self.get_rt_mut(address) {
+            rt.record_no_response();
+            Ok(())
+        } else {
+            Err(crate::error::ParseError::invalid_address(
+                "RT not registered".to_string(),
+            ))
+        }
+    }
+
+    /// Get the fraction of an RT's recent commands that it answered
+    ///
+    /// Covers the last [`RemoteTerminal::RESPONSE_WINDOW`] commands.
+    /// Returns 0.0 for unregistered RTs and RTs with no recorded commands.
+    pub fn response_rate(&self, address: Address) -> f32 {
+        self.get_rt(address)
+            .map(|rt| rt.response_rate())
+            .unwrap_or(0.0)
+    }
+
+    /// Record a failed transaction with an RT, logging the error event
+    pub fn record_rt_error_event(
+        &mut self,
+        address: Address,
+        timestamp_us: u64,
+        error: ParseError,
+    ) -> Result<()> {
+        if let Some(rt) = self.get_rt_mut(address) {
+            rt.record_error_event(timestamp_us, error);
+            Ok(())
+        } else {
+            Err(crate::error::ParseError::invalid_address(
+                "RT not registered".to_string(),
+            ))
+        }
+    }
+
+    /// Get the logged error events for an RT, oldest first
+    ///
+    /// Returns an empty list for unregistered RTs.
+    pub fn error_log(&self, address: Address) -> Vec<(u64, ParseError)> {
+        self.get_rt(address)
+            .map(|rt| rt.error_log().iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Get the mode codes an RT has accepted, in code order
+    ///
+    /// A mode code counts as accepted once the RT answered a mode command
+    /// carrying it with a status word without Message Error, as seen by
+    /// [`Self::observe_transaction`]. Returns an empty list for
+    /// unregistered RTs.
+    pub fn accepted_mode_codes(&self, address: Address) -> Vec<ModeCode> {
+        self.get_rt(address)
+            .map(|rt| rt.accepted_mode_codes().to_vec())
+            .unwrap_or_default()
+    }
+
     /// Get statistics for a Remote Terminal
     pub fn get_rt_stats(&self, address: Address) -> Option<RTStats> {
         self.get_rt(address).map(|rt| RTStats {
@@ -175,10 +481,22 @@ impl BusController {
             } else {
                 0.0
             },
-            is_responding: rt.is_responding(self.response_timeout),
+            is_responding: self.rt_is_responding(rt),
         })
     }
 
+    /// Check whether an RT was seen within the response timeout
+    #[cfg(feature = "std")]
+    fn rt_is_responding(&self, rt: &RemoteTerminal) -> bool {
+        rt.is_responding(self.response_timeout)
+    }
+
+    /// Without the `std` feature there is no clock, so no RT is responding
+    #[cfg(not(feature = "std"))]
+    fn rt_is_responding(&self, _rt: &RemoteTerminal) -> bool {
+        false
+    }
+
     /// Get statistics for all Remote Terminals
     pub fn get_all_stats(&self) -> Vec<RTStats> {
         self.list_rts()
@@ -186,6 +504,30 @@ impl BusController {
             .filter_map(|rt| self.get_rt_stats(rt.address))
             .collect()
     }
+
+    /// Get the compact status byte of every RT, ordered by address
+    pub fn status_bytes(&self) -> Vec<(Address, u8)> {
+        let mut bytes: Vec<(Address, u8)> = self
+            .get_all_stats()
+            .iter()
+            .map(|stats| (stats.address, stats.status_byte()))
+            .collect();
+        bytes.sort_by_key(|(address, _)| *address);
+        bytes
+    }
+
+    /// Get error counts per RT, highest first
+    ///
+    /// RTs with equal error counts are ordered by address.
+    pub fn error_leaderboard(&self) -> Vec<(Address, u32)> {
+        let mut board: Vec<(Address, u32)> = self
+            .remote_terminals
+            .values()
+            .map(|rt| (rt.address, rt.error_count))
+            .collect();
+        board.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        board
+    }
 }
 
 /// Statistics for a Remote Terminal
@@ -206,10 +548,102 @@ pub struct RTStats {
     pub is_responding: bool,
 }
 
+impl RTStats {
+    /// Status byte flag set while the RT is responding
+    pub const RESPONDING_FLAG: u8 = 0x80;
+
+    /// Encode the state and responding flag into a single byte
+    ///
+    /// Bits 1-0 hold the state (`0` idle, `1` busy, `2` error,
+    /// `3` no response), bit 7 is set while the RT is responding and the
+    /// remaining bits are zero.
+    pub fn status_byte(&self) -> u8 {
+        let state = match self.state {
+            RTState::Idle => 0,
+            RTState::Busy => 1,
+            RTState::Error => 2,
+            RTState::NoResponse => 3,
+        };
+        if self.is_responding {
+            state | Self::RESPONDING_FLAG
+        } else {
+            state
+        }
+    }
+}
+
+/// Compute the maximum number of messages that fit in a minor frame
+///
+/// `avg_words` is the average number of words per message, counting the
+/// command, status and data words. Each message is budgeted its word time
+/// plus the maximum RT response time and the minimum intermessage gap.
+pub fn max_messages_per_frame(frame_period: Duration, avg_words: usize) -> usize {
+    let message_us = avg_words as u128 * crate::spec::WORD_TIME_US as u128
+        + crate::spec::MAX_RESPONSE_TIME_US as u128
+        + crate::spec::MIN_INTERMESSAGE_GAP_US as u128;
+    (frame_period.as_micros() / message_us) as usize
+}
+
+/// Inclusive bounds on the number of data words in a message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WordCountBounds {
+    /// Minimum number of data words
+    pub min: usize,
+    /// Maximum number of data words
+    pub max: usize,
+}
+
+impl WordCountBounds {
+    /// Create new bounds
+    pub fn new(min: usize, max: usize) -> Self {
+        WordCountBounds { min, max }
+    }
+
+    /// Check whether a count lies within the bounds
+    pub fn contains(&self, count: usize) -> bool {
+        (self.min..=self.max).contains(&count)
+    }
+}
+
+/// Per-format data word count limits for transaction validation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ValidationConfig {
+    /// Bounds for BC-to-RT (receive) messages
+    pub bc_to_rt: WordCountBounds,
+    /// Bounds for RT-to-BC (transmit) messages
+    pub rt_to_bc: WordCountBounds,
+    /// Bounds for RT-to-RT transfers
+    pub rt_to_rt: WordCountBounds,
+    /// Bounds for command/status messages
+    pub command_status: WordCountBounds,
+    /// Bounds for command-only messages
+    pub command_only: WordCountBounds,
+    /// Bounds for status-only messages
+    pub status: WordCountBounds,
+}
+
+impl Default for ValidationConfig {
+    fn default() -> Self {
+        ValidationConfig {
+            bc_to_rt: WordCountBounds::new(0, 32),
+            rt_to_bc: WordCountBounds::new(0, 32),
+            rt_to_rt: WordCountBounds::new(0, 32),
+            command_status: WordCountBounds::new(0, 0),
+            command_only: WordCountBounds::new(0, 0),
+            status: WordCountBounds::new(0, 0),
+        }
+    }
+}
+
 /// Message validator for protocol compliance
 pub struct MessageValidator;
 
 impl MessageValidator {
+    /// Instrumentation bit (bit time 10) within the 16-bit word field
+    pub const INSTRUMENTATION_BIT: u16 = 0x0200;
+
     /// Validate message addressing
     pub fn validate_address(address: Address) -> Result<()> {
         // All addresses 0-31 are valid in different contexts
@@ -237,6 +671,275 @@ impl MessageValidator {
         }
         Ok(())
     }
+
+    /// Validate that no status word answers a broadcast command
+    ///
+    /// Flags a status word carried in the same message as a broadcast
+    /// command, and a status-only transaction timestamped within `window`
+    /// after a broadcast command. In a broadcast RT-to-RT transfer only a
+    /// receiving status is flagged, since the transmitting RT answers its
+    /// own transmit command. Transactions are assumed to be in
+    /// chronological order.
+    pub fn validate_broadcast_responses(
+        transactions: &[Transaction],
+        window: Duration,
+    ) -> Result<()> {
+        let window_us = window.as_micros() as u64;
+        let mut last_broadcast: Option<u64> = None;
+
+        for (index, transaction) in transactions.iter().enumerate() {
+            match &transaction.message {
+                Message::RtToBc { command, .. }
+                | Message::CommandStatus { command, .. }
+                | Message::BcToRt {
+                    command,
+                    status: Some(_),
+                    ..
+                }
+                | Message::RtToRt {
+                    receive_command: command,
+                    receiving_status: Some(_),
+                    ..
+                } if command.address.is_broadcast() => {
+                    return Err(crate::error::ParseError::validation_error(format!(
+                        "Transaction {}: status word answers a broadcast command",
+                        index
+                    )));
+                }
+                Message::Status(_) => {
+                    let in_window = match (last_broadcast, transaction.timestamp_us) {
+                        (Some(sent), Some(seen)) => seen.saturating_sub(sent) <= window_us,
+                        _ => false,
+                    };
+                    if in_window {
+                        return Err(crate::error::ParseError::validation_error(format!(
+                            "Transaction {}: status word within {} us of a broadcast command",
+                            index, window_us
+                        )));
+                    }
+                }
+                message => {
+                    last_broadcast = message
+                        .command()
+                        .filter(|command| command.address.is_broadcast())
+                        .and(transaction.timestamp_us);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate that a command word has the instrumentation bit clear
+    ///
+    /// Buses that use the instrumentation bit to tell command words from
+    /// status words require it to be clear in every command word.
+    pub fn validate_instrumentation_bit(word: &Word) -> Result<()> {
+        if word.word_type() != WordType::Command {
+            return Err(crate::error::ParseError::invalid_message_type(format!(
+                "Expected a command word, got {}",
+                word.word_type()
+            )));
+        }
+        if word.get_data_bits() & Self::INSTRUMENTATION_BIT != 0 {
+            return Err(crate::error::ParseError::validation_error(format!(
+                "Instrumentation bit set in command word 0x{:04X}",
+                word.get_data_bits()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Validate that exactly one RT answered a command
+    ///
+    /// `words` is the word sequence of one transaction, starting with its
+    /// command word. A non-broadcast command must be followed by exactly
+    /// one status word, sent by the addressed RT; none means the RT did
+    /// not respond and more than one points to a bus collision. Broadcast
+    /// commands are not checked here.
+    pub fn validate_single_response(words: &[Word]) -> Result<()> {
+        let command = match words.first() {
+            Some(word) if word.word_type() == WordType::Command => Command::from_word(word)?,
+            _ => {
+                return Err(crate::error::ParseError::invalid_message_type(
+                    "Transaction must start with a command word".to_string(),
+                ))
+            }
+        };
+        if command.address.is_broadcast() {
+            return Ok(());
+        }
+
+        let statuses: Vec<&Word> = words[1..]
+            .iter()
+            .filter(|word| word.word_type() == WordType::Status)
+            .collect();
+        match statuses.as_slice() {
+            [] => Err(crate::error::ParseError::validation_error(format!(
+                "No status word from {}",
+                command.address
+            ))),
+            [word] => {
+                let status = StatusWord::from_word(word)?;
+                if status.address != command.address {
+                    return Err(crate::error::ParseError::validation_error(format!(
+                        "Status word from {} answers a command to {}",
+                        status.address, command.address
+                    )));
+                }
+                Ok(())
+            }
+            _ => Err(crate::error::ParseError::validation_error(format!(
+                "{} status words answer a command to {}",
+                statuses.len(),
+                command.address
+            ))),
+        }
+    }
+
+    /// Validate that a message's status word comes from the commanded RT
+    ///
+    /// A status word carrying another RT's address means a terminal
+    /// answered a command that was not addressed to it. Messages without
+    /// both a command and a status word are accepted.
+    pub fn validate_status_address(message: &Message) -> Result<()> {
+        let responses = match message {
+            Message::RtToBc {
+                command, status, ..
+            }
+            | Message::CommandStatus { command, status }
+            | Message::BcToRt {
+                command,
+                status: Some(status),
+                ..
+            } => vec![(command, status)],
+            Message::RtToRt {
+                receive_command,
+                transmit_command,
+                receiving_status,
+                transmitting_status,
+                ..
+            } => {
+                let mut responses = vec![(transmit_command, transmitting_status)];
+                if let Some(receiving_status) = receiving_status {
+                    responses.push((receive_command, receiving_status));
+                }
+                responses
+            }
+            _ => return Ok(()),
+        };
+        for (command, status) in responses {
+            if status.address != command.address {
+                return Err(crate::error::ParseError::validation_error(format!(
+                    "Status word from {} answers a command to {}",
+                    status.address, command.address
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate that no mode command uses a reserved mode code (9-15)
+    ///
+    /// With `strict` set, a mode command carrying one of the reserved codes
+    /// is rejected as an illegal command. Otherwise it is accepted as
+    /// parsed: the raw code stays in the word count field, and
+    /// [`Command::mode_code`] returns `None` for it.
+    pub fn validate_mode_code(message: &Message, strict: bool) -> Result<()> {
+        if !strict {
+            return Ok(());
+        }
+        let transmit_command = match message {
+            Message::RtToRt {
+                transmit_command, ..
+            } => Some(transmit_command),
+            _ => None,
+        };
+        for command in message.command().into_iter().chain(transmit_command) {
+            if command.is_mode_command() && (9..=15).contains(&command.word_count) {
+                return Err(crate::error::ParseError::invalid_command(format!(
+                    "Reserved mode code {} sent to {}",
+                    command.word_count, command.address
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate the timing of a Dynamic Bus Control handoff
+    ///
+    /// `acceptance` must be a Dynamic Bus Control mode command answered by
+    /// a status word with the Dynamic Bus Control Acceptance bit set.
+    /// `first` is the first transaction issued by the new controller; it
+    /// must carry a command and start no later than `window` after the
+    /// acceptance. Both transactions need timestamps.
+    pub fn validate_dbc_handoff(
+        acceptance: &Transaction,
+        first: &Transaction,
+        window: Duration,
+    ) -> Result<()> {
+        let accepted = match &acceptance.message {
+            Message::CommandStatus { command, status } => {
+                command.mode_code() == Some(ModeCode::DynamicBusControl)
+                    && status.address == command.address
+                    && status.flags.dynamic_bus_control
+            }
+            _ => false,
+        };
+        if !accepted {
+            return Err(crate::error::ParseError::validation_error(
+                "Transaction is not an accepted Dynamic Bus Control command".to_string(),
+            ));
+        }
+        if first.message.command().is_none() {
+            return Err(crate::error::ParseError::validation_error(
+                "New controller's first transaction carries no command".to_string(),
+            ));
+        }
+
+        let (Some(accepted_at), Some(started_at)) = (acceptance.timestamp_us, first.timestamp_us)
+        else {
+            return Err(crate::error::ParseError::validation_error(
+                "Dynamic Bus Control handoff needs timestamped transactions".to_string(),
+            ));
+        };
+        let gap_us = started_at.checked_sub(accepted_at).ok_or_else(|| {
+            crate::error::ParseError::validation_error(
+                "New controller started before the handoff was accepted".to_string(),
+            )
+        })?;
+        if u128::from(gap_us) > window.as_micros() {
+            return Err(crate::error::ParseError::validation_error(format!(
+                "New controller took over {} us after the handoff, allowed {} us",
+                gap_us,
+                window.as_micros()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Validate a transaction's data word count against the configured bounds
+    pub fn validate_transaction(
+        transaction: &Transaction,
+        config: &ValidationConfig,
+    ) -> Result<()> {
+        let (format, bounds) = match transaction.message {
+            Message::BcToRt { .. } => ("BC-to-RT", config.bc_to_rt),
+            Message::RtToBc { .. } => ("RT-to-BC", config.rt_to_bc),
+            Message::RtToRt { .. } => ("RT-to-RT", config.rt_to_rt),
+            Message::CommandStatus { .. } => ("command/status", config.command_status),
+            Message::CommandOnly(_) => ("command-only", config.command_only),
+            Message::Status(_) => ("status", config.status),
+        };
+        let count = transaction.message.data_word_count().unwrap_or(0);
+
+        if !bounds.contains(count) {
+            return Err(crate::error::ParseError::validation_error(format!(
+                "{} message has {} data words, expected {}-{}",
+                format, count, bounds.min, bounds.max
+            )));
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -282,6 +985,190 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_stale_rts() -> Result<()> {
+        let mut bc = BusController::new(Bus::BusA);
+        bc.register_rts(&[1, 2, 3])?;
+
+        // RT-1 seen just now, RT-2 seen long ago, RT-3 never seen
+        bc.record_rt_success(Address::new(1)?)?;
+        bc.get_rt_mut(Address::new(2)?).unwrap().last_seen =
+            Instant::now().checked_sub(Duration::from_secs(10));
+
+        let stale = bc.stale_rts(Duration::from_secs(5));
+        assert_eq!(stale, vec![Address::new(2)?, Address::new(3)?]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_error_leaderboard() -> Result<()> {
+        let mut bc = BusController::new(Bus::BusA);
+        bc.register_rts(&[1, 2, 3, 4])?;
+        for (addr, errors) in [(1, 2), (2, 5), (3, 0), (4, 2)] {
+            for _ in 0..errors {
+                bc.record_rt_error(Address::new(addr)?)?;
+            }
+        }
+
+        let board: Vec<(u8, u32)> = bc
+            .error_leaderboard()
+            .into_iter()
+            .map(|(address, errors)| (address.value(), errors))
+            .collect();
+        assert_eq!(board, vec![(2, 5), (1, 2), (4, 2), (3, 0)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_response_rate() -> Result<()> {
+        let mut bc = BusController::new(Bus::BusA);
+        let address = Address::new(4)?;
+        bc.register_rt(address)?;
+        assert_eq!(bc.response_rate(address), 0.0);
+
+        for responded in [true, true, false, true] {
+            if responded {
+                bc.record_rt_success(address)?;
+            } else {
+                bc.record_rt_no_response(address)?;
+            }
+        }
+        assert_eq!(bc.response_rate(address), 0.75);
+        assert_eq!(bc.get_rt(address).unwrap().state, RTState::Idle);
+
+        // Only the most recent window counts
+        for _ in 0..RemoteTerminal::RESPONSE_WINDOW {
+            bc.record_rt_no_response(address)?;
+        }
+        bc.record_rt_success(address)?;
+        let expected = 1.0 / RemoteTerminal::RESPONSE_WINDOW as f32;
+        assert_eq!(bc.response_rate(address), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_stats() -> Result<()> {
+        let mut node_a = BusController::new(Bus::BusA);
+        node_a.register_rts(&[1, 2, 4])?;
+        node_a.record_rt_success(Address::new(1)?)?;
+        node_a.record_rt_error_event(Address::new(2)?, 300, ParseError::parity_error("a"))?;
+
+        let mut node_b = BusController::new(Bus::BusA);
+        node_b.register_rts(&[2, 3, 4])?;
+        node_b.record_rt_success(Address::new(2)?)?;
+        node_b.record_rt_success(Address::new(2)?)?;
+        node_b.record_rt_error_event(Address::new(2)?, 100, ParseError::parity_error("b"))?;
+        node_b.record_rt_error(Address::new(3)?)?;
+        node_b.record_rt_error(Address::new(4)?)?;
+
+        node_a.merge_stats(&node_b);
+        assert_eq!(node_a.rt_count(), 4);
+
+        let rt2 = node_a.get_rt(Address::new(2)?).unwrap();
+        assert_eq!((rt2.success_count, rt2.error_count), (2, 2));
+        #[cfg(feature = "std")]
+        assert_eq!(
+            rt2.last_seen,
+            node_b.get_rt(Address::new(2)?).unwrap().last_seen
+        );
+        let timestamps: Vec<u64> = rt2.error_log().iter().map(|(t, _)| *t).collect();
+        assert_eq!(timestamps, vec![100, 300]);
+
+        let rt1 = node_a.get_rt(Address::new(1)?).unwrap();
+        assert_eq!((rt1.success_count, rt1.error_count), (1, 0));
+        let rt3 = node_a.get_rt(Address::new(3)?).unwrap();
+        assert_eq!((rt3.success_count, rt3.error_count), (0, 1));
+        // Only node_b has seen RT 4, so its state wins
+        let rt4 = node_a.get_rt(Address::new(4)?).unwrap();
+        assert_eq!(rt4.state, RTState::Error);
+        Ok(())
+    }
+
+    #[test]
+    fn test_status_byte() -> Result<()> {
+        let mut stats = RTStats {
+            address: Address::new(1)?,
+            state: RTState::Idle,
+            success_count: 0,
+            error_count: 0,
+            error_rate: 0.0,
+            is_responding: false,
+        };
+        for (state, byte) in [
+            (RTState::Idle, 0x00),
+            (RTState::Busy, 0x01),
+            (RTState::Error, 0x02),
+            (RTState::NoResponse, 0x03),
+        ] {
+            stats.state = state;
+            stats.is_responding = false;
+            assert_eq!(stats.status_byte(), byte);
+            stats.is_responding = true;
+            assert_eq!(stats.status_byte(), byte | RTStats::RESPONDING_FLAG);
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_status_bytes() -> Result<()> {
+        let mut bc = BusController::new(Bus::BusA);
+        // Long enough that the RTs still count as responding when read
+        bc.response_timeout = Duration::from_secs(3600);
+        bc.register_rts(&[7, 2, 4])?;
+        bc.record_rt_success(Address::new(2)?)?;
+        bc.record_rt_error(Address::new(4)?)?;
+        bc.get_rt_mut(Address::new(7)?).unwrap().state = RTState::NoResponse;
+
+        let bytes: Vec<(u8, u8)> = bc
+            .status_bytes()
+            .into_iter()
+            .map(|(address, byte)| (address.value(), byte))
+            .collect();
+        assert_eq!(bytes, vec![(2, 0x80), (4, 0x82), (7, 0x03)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_error_log() -> Result<()> {
+        let mut bc = BusController::new(Bus::BusA);
+        let address = Address::new(5)?;
+        bc.register_rt(address)?;
+
+        bc.record_rt_error_event(address, 100, ParseError::parity_error("first"))?;
+        bc.record_rt_error_event(address, 250, ParseError::invalid_word("second"))?;
+        bc.record_rt_error_event(address, 400, ParseError::parity_error("third"))?;
+
+        let log = bc.error_log(address);
+        let timestamps: Vec<u64> = log.iter().map(|(t, _)| *t).collect();
+        assert_eq!(timestamps, vec![100, 250, 400]);
+        assert_eq!(log[1].1, ParseError::invalid_word("second"));
+        assert_eq!(bc.get_rt(address).unwrap().error_count, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_error_log_is_bounded() -> Result<()> {
+        let mut rt = RemoteTerminal::new(Address::new(5)?);
+        for t in 0..(RemoteTerminal::ERROR_LOG_CAPACITY as u64 + 10) {
+            rt.record_error_event(t, ParseError::parity_error("bad"));
+        }
+        assert_eq!(rt.error_log().len(), RemoteTerminal::ERROR_LOG_CAPACITY);
+        assert_eq!(rt.error_log().front().unwrap().0, 10);
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_messages_per_frame() {
+        let frame = Duration::from_millis(1);
+
+        // Command + 16 data words + status: 360 us on the wire + 16 us overhead
+        assert_eq!(max_messages_per_frame(frame, 18), 2);
+        // Command + 2 data words + status: 80 us + 16 us
+        assert_eq!(max_messages_per_frame(frame, 4), 10);
+    }
+
     #[test]
     fn test_message_validator() -> Result<()> {
         MessageValidator::validate_word_count(16)?;
@@ -291,4 +1178,434 @@ mod tests {
         assert!(MessageValidator::validate_sub_address(32).is_err());
         Ok(())
     }
+
+    fn data_transaction(count: usize) -> Result<Transaction> {
+        use crate::core::{Word, WordType};
+        use crate::message::{Command, CommandType, SubAddress};
+
+        let command = Command::new(
+            Address::new(5)?,
+            CommandType::Receive,
+            SubAddress::new(1)?,
+            count as u16,
+        )?;
+        let word = Word::from_data_bits(0, WordType::Data);
+
+        Ok(Transaction {
+            bus: Bus::BusA,
+            message: Message::BcToRt {
+                command,
+                data_words: vec![word; count],
+                status: None,
+            },
+            timestamp_us: None,
+            response_gap_us: None,
+            sequence: None,
+            decoded: None,
+        })
+    }
+
+    #[test]
+    fn test_is_late_response() -> Result<()> {
+        let bc = BusController::new(Bus::BusA);
+        let mut transaction = data_transaction(1)?;
+        assert!(!bc.is_late_response(&transaction));
+
+        transaction.response_gap_us = Some(8);
+        assert!(!bc.is_late_response(&transaction));
+        transaction.response_gap_us = Some(14);
+        assert!(bc.is_late_response(&transaction));
+        Ok(())
+    }
+
+    #[test]
+    fn test_observe_transaction() -> Result<()> {
+        use crate::message::{Command, CommandType, StatusFlags, StatusWord, SubAddress};
+
+        let mut bc = BusController::new(Bus::BusA);
+        bc.register_rts(&[3, 4])?;
+        let rt3 = Address::new(3)?;
+        let command = Command::new(rt3, CommandType::Transmit, SubAddress::new(1)?, 1)?;
+        let flags = StatusFlags::new(false, false, false, false, false);
+        let observed = |message, response_gap_us| Transaction {
+            bus: Bus::BusA,
+            message,
+            timestamp_us: None,
+            response_gap_us,
+            sequence: None,
+            decoded: None,
+        };
+
+        // Answered within the 12 us timeout
+        let status = StatusWord::new(rt3, flags, 0)?;
+        bc.observe_transaction(&observed(
+            Message::CommandStatus {
+                command: command.clone(),
+                status,
+            },
+            Some(6),
+        ))?;
+        let stats = bc.get_rt_stats(rt3).unwrap();
+        assert_eq!((stats.success_count, stats.state), (1, RTState::Idle));
+
+        // No status at all, then a late one
+        bc.observe_transaction(&observed(Message::CommandOnly(command.clone()), None))?;
+        assert_eq!(bc.get_rt_stats(rt3).unwrap().state, RTState::NoResponse);
+        bc.observe_transaction(&observed(
+            Message::CommandStatus {
+                command: command.clone(),
+                status,
+            },
+            Some(20),
+        ))?;
+        assert_eq!(bc.get_rt_stats(rt3).unwrap().success_count, 1);
+        assert!((bc.response_rate(rt3) - 1.0 / 3.0).abs() < 1e-6);
+
+        // A status from another RT counts against the commanded one
+        let wrong = StatusWord::new(Address::new(4)?, flags, 0)?;
+        bc.observe_transaction(&observed(
+            Message::CommandStatus {
+                command,
+                status: wrong,
+            },
+            Some(6),
+        ))?;
+        assert_eq!(bc.get_rt_stats(rt3).unwrap().error_count, 1);
+
+        let unknown = Command::new(
+            Address::new(9)?,
+            CommandType::Transmit,
+            SubAddress::new(1)?,
+            1,
+        )?;
+        assert!(bc
+            .observe_transaction(&observed(Message::CommandOnly(unknown), None))
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_observe_bc_to_rt_status() -> Result<()> {
+        use crate::message::{Command, CommandType, StatusFlags, StatusWord, SubAddress};
+        use crate::parser::Parser;
+
+        let mut bc = BusController::new(Bus::BusA);
+        bc.register_rts(&[6])?;
+        let rt6 = Address::new(6)?;
+        let command = Command::new(rt6, CommandType::Receive, SubAddress::new(2)?, 2)?;
+        let status = StatusWord::new(rt6, StatusFlags::new(false, false, false, false, false), 0)?;
+
+        let parser = Parser::new(Bus::BusA);
+        let mut capture = parser.encode_command(&command)?;
+        capture.extend(parser.encode_data_words(&[0x1111, 0x2222])?);
+        capture.extend(parser.encode_status(&status)?);
+        let transaction = parser.parse_transaction(&capture)?;
+        assert!(matches!(
+            transaction.message,
+            Message::BcToRt {
+                status: Some(_),
+                ..
+            }
+        ));
+
+        bc.observe_transaction(&transaction)?;
+        let stats = bc.get_rt_stats(rt6).unwrap();
+        assert_eq!((stats.success_count, stats.state), (1, RTState::Idle));
+        assert_eq!(bc.response_rate(rt6), 1.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_accepted_mode_codes() -> Result<()> {
+        use crate::message::{StatusFlags, StatusWord};
+        use crate::parser::Parser;
+
+        let mut bc = BusController::new(Bus::BusA);
+        bc.register_rts(&[6])?;
+        let rt6 = Address::new(6)?;
+        let parser = Parser::new(Bus::BusA);
+        let ok = StatusWord::new(rt6, StatusFlags::new(false, false, false, false, false), 0)?;
+        let message_error =
+            StatusWord::new(rt6, StatusFlags::new(false, false, false, false, true), 0)?;
+
+        let mut observe = |code: ModeCode, status: Option<&StatusWord>| -> Result<()> {
+            let command = Command::new_mode_code(rt6, CommandType::Transmit, code);
+            let mut capture = parser.encode_command(&command)?;
+            if let Some(status) = status {
+                capture.extend(parser.encode_status(status)?);
+            }
+            bc.observe_transaction(&parser.parse_transaction(&capture)?)
+        };
+        observe(ModeCode::TransmitterShutdown, Some(&ok))?;
+        observe(ModeCode::Synchronize, Some(&ok))?;
+        observe(ModeCode::TransmitterShutdown, Some(&ok))?;
+        observe(ModeCode::InitiateSelfTest, Some(&message_error))?;
+        observe(ModeCode::TransmitStatusWord, None)?;
+
+        assert_eq!(
+            bc.accepted_mode_codes(rt6),
+            vec![ModeCode::Synchronize, ModeCode::TransmitterShutdown]
+        );
+        assert!(bc.accepted_mode_codes(Address::new(7)?).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_reserved_mode_code() -> Result<()> {
+        use crate::message::SubAddress;
+
+        let rt = Address::new(6)?;
+        for code in 9..=15 {
+            let reserved = Command::new(rt, CommandType::Transmit, SubAddress::new(31)?, code)?;
+            let message = Message::CommandOnly(reserved.clone());
+            assert!(MessageValidator::validate_mode_code(&message, true).is_err());
+
+            // Lenient mode keeps the reserved code as parsed
+            MessageValidator::validate_mode_code(&message, false)?;
+            assert_eq!(reserved.word_count, code);
+            assert_eq!(reserved.mode_code(), None);
+        }
+
+        let reset =
+            Command::new_mode_code(rt, CommandType::Transmit, ModeCode::ResetRemoteTerminal);
+        MessageValidator::validate_mode_code(&Message::CommandOnly(reset), true)?;
+        // Sub-address 9 is an ordinary transfer, whatever its word count
+        let transfer = Command::new(rt, CommandType::Transmit, SubAddress::new(9)?, 9)?;
+        MessageValidator::validate_mode_code(&Message::CommandOnly(transfer), true)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_service_request_poll() -> Result<()> {
+        use crate::message::StatusFlags;
+
+        let bc = BusController::new(Bus::BusA);
+        let rt = Address::new(12)?;
+        let mut flags = StatusFlags::new(false, false, false, false, false);
+        assert_eq!(
+            bc.service_request_poll(&StatusWord::new(rt, flags, 0)?),
+            None
+        );
+
+        flags.service_request = true;
+        let poll = bc
+            .service_request_poll(&StatusWord::new(rt, flags, 0)?)
+            .unwrap();
+        assert_eq!(poll.address, rt);
+        assert_eq!(poll.command_type, CommandType::Transmit);
+        assert_eq!(poll.mode_code(), Some(ModeCode::TransmitVectorWord));
+        assert_eq!(poll.expected_data_words(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_broadcast_followed_by_status() -> Result<()> {
+        use crate::message::{Command, CommandType, StatusFlags, StatusWord, SubAddress};
+
+        let broadcast = Command::new(
+            Address::broadcast(),
+            CommandType::Receive,
+            SubAddress::new(1)?,
+            1,
+        )?;
+        let status = StatusWord::new(
+            Address::new(5)?,
+            StatusFlags::new(false, false, false, false, false),
+            0,
+        )?;
+        let at = |timestamp_us, message| Transaction {
+            bus: Bus::BusA,
+            message,
+            timestamp_us: Some(timestamp_us),
+            response_gap_us: None,
+            sequence: None,
+            decoded: None,
+        };
+        let window = Duration::from_micros(14);
+
+        let clean = vec![
+            at(0, Message::CommandOnly(broadcast.clone())),
+            at(100, Message::Status(status)),
+        ];
+        MessageValidator::validate_broadcast_responses(&clean, window)?;
+
+        let answered = vec![
+            at(0, Message::CommandOnly(broadcast.clone())),
+            at(10, Message::Status(status)),
+        ];
+        assert!(MessageValidator::validate_broadcast_responses(&answered, window).is_err());
+
+        let embedded = vec![at(
+            0,
+            Message::CommandStatus {
+                command: broadcast.clone(),
+                status,
+            },
+        )];
+        assert!(MessageValidator::validate_broadcast_responses(&embedded, window).is_err());
+
+        // In a broadcast RT-to-RT transfer the transmitting RT does answer
+        let transmit = Command::new(
+            Address::new(5)?,
+            CommandType::Transmit,
+            SubAddress::new(2)?,
+            1,
+        )?;
+        let rt_to_rt = |receiving_status| Message::RtToRt {
+            receive_command: broadcast.clone(),
+            transmit_command: transmit.clone(),
+            data_words: vec![Word::from_data_bits(0x1234, WordType::Data)],
+            receiving_status,
+            transmitting_status: status,
+        };
+        MessageValidator::validate_broadcast_responses(&[at(0, rt_to_rt(None))], window)?;
+        let receiver_answered = vec![at(0, rt_to_rt(Some(status)))];
+        assert!(
+            MessageValidator::validate_broadcast_responses(&receiver_answered, window).is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_dbc_handoff() -> Result<()> {
+        use crate::message::{StatusFlags, SubAddress};
+
+        let rt7 = Address::new(7)?;
+        let dbc = Command::new_mode_code(rt7, CommandType::Transmit, ModeCode::DynamicBusControl);
+        let accepting = StatusWord::new(
+            rt7,
+            StatusFlags {
+                dynamic_bus_control: true,
+                ..StatusFlags::default()
+            },
+            0,
+        )?;
+        let at = |timestamp_us, message| Transaction {
+            bus: Bus::BusA,
+            message,
+            timestamp_us: Some(timestamp_us),
+            response_gap_us: None,
+            sequence: None,
+            decoded: None,
+        };
+        let acceptance = at(
+            1_000,
+            Message::CommandStatus {
+                command: dbc.clone(),
+                status: accepting,
+            },
+        );
+        let poll = Command::new(
+            Address::new(3)?,
+            CommandType::Transmit,
+            SubAddress::new(1)?,
+            1,
+        )?;
+        let window = Duration::from_micros(500);
+
+        // Compliant handoff
+        let first = at(1_400, Message::CommandOnly(poll.clone()));
+        MessageValidator::validate_dbc_handoff(&acceptance, &first, window)?;
+
+        // Late handoff
+        let late = at(1_600, Message::CommandOnly(poll.clone()));
+        assert!(MessageValidator::validate_dbc_handoff(&acceptance, &late, window).is_err());
+
+        // A refused handoff is not an acceptance
+        let refused = at(
+            1_000,
+            Message::CommandStatus {
+                command: dbc,
+                status: StatusWord::new(rt7, StatusFlags::default(), 0)?,
+            },
+        );
+        assert!(MessageValidator::validate_dbc_handoff(&refused, &first, window).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_instrumentation_bit() -> Result<()> {
+        let command_word = |field: u16| Word::from_data_bits(field, WordType::Command);
+
+        MessageValidator::validate_instrumentation_bit(&command_word(0x2843))?;
+        assert!(MessageValidator::validate_instrumentation_bit(&command_word(0x2A43)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_single_response() -> Result<()> {
+        use crate::message::{CommandType, StatusFlags, SubAddress};
+
+        let command = Command::new(
+            Address::new(5)?,
+            CommandType::Receive,
+            SubAddress::new(1)?,
+            1,
+        )?
+        .to_word()?;
+        let status = |rt| -> Result<Word> {
+            StatusWord::new(
+                Address::new(rt)?,
+                StatusFlags::new(false, false, false, false, false),
+                0,
+            )?
+            .to_word()
+        };
+        let data = Word::from_data_bits(0, WordType::Data);
+
+        MessageValidator::validate_single_response(&[command, data, status(5)?])?;
+        assert!(MessageValidator::validate_single_response(&[command, data]).is_err());
+        let collision = [command, data, status(5)?, status(6)?];
+        assert!(MessageValidator::validate_single_response(&collision).is_err());
+        assert!(MessageValidator::validate_single_response(&[command, data, status(6)?]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_status_address() -> Result<()> {
+        use crate::message::{CommandType, StatusFlags, SubAddress};
+
+        let command = Command::new(
+            Address::new(5)?,
+            CommandType::Receive,
+            SubAddress::new(1)?,
+            1,
+        )?;
+        let reply = |rt| -> Result<Message> {
+            let status = StatusWord::new(
+                Address::new(rt)?,
+                StatusFlags::new(false, false, false, false, false),
+                0,
+            )?;
+            Ok(Message::CommandStatus {
+                command: command.clone(),
+                status,
+            })
+        };
+
+        MessageValidator::validate_status_address(&reply(5)?)?;
+        assert!(MessageValidator::validate_status_address(&reply(6)?).is_err());
+        MessageValidator::validate_status_address(&Message::CommandOnly(command.clone()))?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_transaction_compliant() -> Result<()> {
+        let config = ValidationConfig::default();
+        MessageValidator::validate_transaction(&data_transaction(4)?, &config)?;
+        MessageValidator::validate_transaction(&data_transaction(32)?, &config)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_transaction_non_compliant() -> Result<()> {
+        let config = ValidationConfig {
+            bc_to_rt: WordCountBounds::new(1, 8),
+            ..ValidationConfig::default()
+        };
+        assert!(MessageValidator::validate_transaction(&data_transaction(0)?, &config).is_err());
+        assert!(MessageValidator::validate_transaction(&data_transaction(9)?, &config).is_err());
+        MessageValidator::validate_transaction(&data_transaction(8)?, &config)?;
+        Ok(())
+    }
 }